@@ -1,12 +1,18 @@
 pub use sea_orm_migration::prelude::*;
 
 mod m20220101_000001_create_cake_table;
+mod m20230501_000001_add_cake_name_unique_index;
+mod m20230601_000001_add_cake_slug;
 
 pub struct Migrator;
 
 #[async_trait::async_trait]
 impl MigratorTrait for Migrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
-        vec![Box::new(m20220101_000001_create_cake_table::Migration)]
+        vec![
+            Box::new(m20220101_000001_create_cake_table::Migration),
+            Box::new(m20230501_000001_add_cake_name_unique_index::Migration),
+            Box::new(m20230601_000001_add_cake_slug::Migration),
+        ]
     }
 }
@@ -0,0 +1,42 @@
+//! Maps database, patch & validation errors to responses.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use sea_orm::DbErr;
+use sea_skipper::{validation::ValidationErrors, DbErrorKind, PatchError};
+
+/// Returns the response [`StatusCode`] for a database error.
+///
+/// Constraint violations caused by the request's data are client errors, while deadlocks, timeouts
+/// and connection errors are reported as temporarily unavailable.
+pub fn db_error_status(err: &DbErr) -> StatusCode {
+    match DbErrorKind::from_db_err(err) {
+        DbErrorKind::UniqueViolation { .. } | DbErrorKind::ForeignKeyViolation { .. } => {
+            StatusCode::CONFLICT
+        }
+        DbErrorKind::NotNullViolation { .. } | DbErrorKind::CheckViolation { .. } => {
+            StatusCode::UNPROCESSABLE_ENTITY
+        }
+        DbErrorKind::Deadlock | DbErrorKind::Timeout | DbErrorKind::Connection => {
+            StatusCode::SERVICE_UNAVAILABLE
+        }
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+/// Returns the response [`StatusCode`] for an error parsing or applying a patch document.
+pub fn patch_error_status(err: &PatchError) -> StatusCode {
+    match err {
+        PatchError::UnsupportedMediaType(_) => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+        err if err.is_unprocessable() => StatusCode::UNPROCESSABLE_ENTITY,
+        _ => StatusCode::BAD_REQUEST,
+    }
+}
+
+/// Returns a `422 Unprocessable Entity` response listing all invalid fields of the request's data.
+pub fn validation_errors_response(errors: ValidationErrors) -> Response {
+    (StatusCode::UNPROCESSABLE_ENTITY, Json(errors)).into_response()
+}
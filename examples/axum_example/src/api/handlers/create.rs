@@ -1,49 +1,75 @@
 use axum::{
-    extract::{Json, State},
+    extract::{Extension, Json, State},
     http::{header, StatusCode},
-    response::IntoResponse,
+    response::{IntoResponse, Response},
 };
 use sea_orm::DatabaseConnection;
-use sea_skipper::{DataTrait, Location, Resource};
+use sea_skipper::{
+    create_idempotent, validation::Validate, BaseUrl, CreateOutcome, DataTrait, Location,
+    ModelCondition, Resource,
+};
 use serde::Serialize;
 
-use crate::db::Mutation;
+use crate::api::error::{db_error_status, validation_errors_response};
 
 /// Handles requests to create a new resource with JSON data from the request body.
 ///
 /// # Response
 /// Responds with `201 Created` status and the created resource's `Location` header.
 ///
+/// Responds with `303 See Other` status and the existing resource's `Location` header when a
+/// resource with equal values already exists (i.e. the create request is idempotent).
+///
+/// `Location` headers are prefixed with the configured [`BaseUrl`].
+///
 /// # Errors
+/// Responds with `409 Conflict` when the data conflicts with a unique value of another resource.
+/// Responds with `422 Unprocessable Entity` and a list of errors when the data is invalid.
 /// Responds with `500 Internal Server Error` when the database mutation fails.
 pub async fn create<R, D>(
     State(db): State<DatabaseConnection>,
+    Extension(base_url): Extension<BaseUrl>,
     Json(data): Json<D>,
-) -> Result<impl IntoResponse, StatusCode>
+) -> Result<Response, Response>
 where
     R: Resource,
-    R::Data: Location,
-    D: DataTrait<R>,
+    R::View: Location,
+    D: DataTrait<R> + ModelCondition<Entity = R> + Validate,
 {
-    // TODO: Update create request example to demo implementation that catches a unique constraint
-    // violation for unique fields and uses `NewModel`'s `ModelCondition::to_all_condition()` to
-    // check if the failed post request would have been idempotent (i.e. data matcing `NewModel` in
-    // the database.) If so return a `303 See Other` redirect versus `409 Conflict`.
-    let result = Mutation::create(&db, data).await;
+    data.validate().map_err(validation_errors_response)?;
+
+    let result = create_idempotent::<R, D, _>(&db, data).await;
     match result {
-        Ok(data) => Ok(created(data)),
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+        Ok(CreateOutcome::Created(data)) => {
+            Ok(created(&base_url, R::View::from(data)).into_response())
+        }
+        Ok(CreateOutcome::AlreadyExists(data)) => {
+            Ok(see_other(&base_url, &R::View::from(data)).into_response())
+        }
+        Ok(CreateOutcome::Conflict(_)) => Err(StatusCode::CONFLICT.into_response()),
+        Err(err) => Err(db_error_status(&err).into_response()),
     }
 }
 
 /// Returns a created response for created [`Data`].
-pub fn created<D>(data: D) -> impl IntoResponse
+pub fn created<D>(base_url: &BaseUrl, data: D) -> impl IntoResponse
 where
     D: Location + Serialize,
 {
     (
         StatusCode::CREATED,
-        [(header::LOCATION, data.location())],
+        [(header::LOCATION, base_url.location(&data))],
         Json(data),
     )
 }
+
+/// Returns a see other response redirecting to existing [`Data`].
+pub fn see_other<D>(base_url: &BaseUrl, data: &D) -> impl IntoResponse
+where
+    D: Location,
+{
+    (
+        StatusCode::SEE_OTHER,
+        [(header::LOCATION, base_url.location(data))],
+    )
+}
@@ -5,19 +5,30 @@ use axum::{
     http::StatusCode,
 };
 use sea_orm::{DatabaseConnection, DeleteResult};
-use sea_skipper::Resource;
+use sea_skipper::{Resource, ResourceId};
 
+use crate::api::error::db_error_status;
 use crate::db::Mutation;
 
-/// Handles requests to delete a resource matching `id`.
-pub async fn delete_by_id<R: Resource>(
+/// Handles requests to delete a resource matching the ID (or an alternate key) in the route's path
+/// parameters.
+///
+/// # Errors
+/// Responds with `400 Bad Request` status when the path parameters are not a valid ID.
+/// Responds with `404 Not Found` status when no resource matching the ID is found in the database.
+pub async fn delete_by_id<R>(
     State(db): State<DatabaseConnection>,
-    Path(id): Path<R::Id>,
-) -> Result<StatusCode, StatusCode> {
-    let result = Mutation::delete_by_id::<R, R::Id>(&db, id).await;
+    Path(segments): Path<Vec<String>>,
+) -> Result<StatusCode, StatusCode>
+where
+    R: Resource,
+    R::Id: ResourceId,
+{
+    let condition = R::path_condition(&segments).map_err(|_| StatusCode::BAD_REQUEST)?;
+    let result = Mutation::delete_one::<R>(&db, condition).await;
     match result {
         Ok(DeleteResult { rows_affected: 0 }) => Err(StatusCode::NOT_FOUND),
         Ok(DeleteResult { rows_affected: 1.. }) => Ok(StatusCode::OK),
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+        Err(err) => Err(db_error_status(&err)),
     }
 }
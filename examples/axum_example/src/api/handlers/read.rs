@@ -6,27 +6,34 @@ use axum::{
     Json,
 };
 use sea_orm::DatabaseConnection;
-use sea_skipper::Resource;
+use sea_skipper::{Resource, ResourceId};
 
+use crate::api::error::db_error_status;
 use crate::db::Query;
 
 /// Handles requests getting a resource by ID.
 ///
+/// The ID is parsed from the route's path parameters (one per primary key column), or a single
+/// path parameter is looked up by the resource's alternate key (e.g. a slug).
+///
 /// # Errors
-/// Responds with `404 Not Found` status when no resource matching `id` is found in the database.
+/// Responds with `400 Bad Request` status when the path parameters are not a valid ID.
+/// Responds with `404 Not Found` status when no resource matching the ID is found in the database.
 /// Responds with `500 Internal Server Error` status when the database query fails.
 pub async fn get_by_id<R: Resource>(
     State(db): State<DatabaseConnection>,
-    Path(id): Path<R::Id>,
-) -> Result<Json<R::Data>, StatusCode>
+    Path(segments): Path<Vec<String>>,
+) -> Result<Json<R::View>, StatusCode>
 where
     R: Resource,
+    R::Id: ResourceId,
 {
-    let result = Query::find_by_id::<R, R::Id>(&db, id).await;
+    let condition = R::path_condition(&segments).map_err(|_| StatusCode::BAD_REQUEST)?;
+    let result = Query::find_one::<R>(&db, condition).await;
     match result {
-        Ok(Some(data)) => Ok(Json(data)),
+        Ok(Some(data)) => Ok(Json(data.into())),
         Ok(None) => Err(StatusCode::NOT_FOUND),
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+        Err(err) => Err(db_error_status(&err)),
     }
 }
 
@@ -36,10 +43,13 @@ where
 /// Responds with `500 Internal Server Error` status when the database query produces an error.
 pub async fn get_collection<R: Resource>(
     State(db): State<DatabaseConnection>,
-) -> Result<Json<Vec<R::Data>>, StatusCode> {
+) -> Result<Json<Vec<R::View>>, StatusCode> {
     Ok(Json(
         Query::find_all::<R>(&db)
             .await
-            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?,
+            .map_err(|err| db_error_status(&err))?
+            .into_iter()
+            .map(R::View::from)
+            .collect(),
     ))
 }
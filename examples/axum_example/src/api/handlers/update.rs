@@ -0,0 +1,90 @@
+//! Request [handlers][axum::handler] for `PATCH` requests.
+
+use axum::{
+    body::Bytes,
+    extract::{Json, Path, State},
+    http::{header::CONTENT_TYPE, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use sea_orm::{DatabaseConnection, IntoActiveModel};
+use sea_skipper::{validation::Validate, PatchDocument, PatchModelTrait, Resource, ResourceId};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::api::error::{db_error_status, patch_error_status, validation_errors_response};
+use crate::db::{Mutation, Query};
+
+/// Handles requests to partially update a resource matching the ID (or an alternate key) in the
+/// route's path parameters with the request body.
+///
+/// The request body is one of:
+/// - JSON data (e.g. a `PatchModel`) with an `application/json` content type.
+/// - A JSON Merge Patch with an `application/merge-patch+json` content type.
+/// - A JSON Patch with an `application/json-patch+json` content type.
+///
+/// Only the fields provided (or changed by the patch document) are changed. Patch documents are
+/// applied to the resource's `View` and can only change the fields of `P` (e.g. a `PatchModel`),
+/// which are validated like JSON data.
+///
+/// # Errors
+/// Responds with `400 Bad Request` when the path parameters are not a valid ID or the request body
+/// is malformed.
+/// Responds with `404 Not Found` status when no resource matching the ID is found in the database.
+/// Responds with `409 Conflict` when the data conflicts with a unique value of another resource.
+/// Responds with `415 Unsupported Media Type` for other content types.
+/// Responds with `422 Unprocessable Entity` when the patch is invalid for the resource (e.g. it
+/// changes the resource's `id` or a read-only field). Invalid data also lists the errors of its
+/// invalid fields.
+/// Responds with `500 Internal Server Error` when the database mutation fails.
+pub async fn update_by_id<R, P>(
+    State(db): State<DatabaseConnection>,
+    Path(segments): Path<Vec<String>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Json<R::View>, Response>
+where
+    R: Resource,
+    R::Id: ResourceId,
+    P: IntoActiveModel<R::ActiveModel>
+        + PatchModelTrait<Entity = R>
+        + DeserializeOwned
+        + Serialize
+        + Validate,
+{
+    let condition =
+        R::path_condition(&segments).map_err(|_| StatusCode::BAD_REQUEST.into_response())?;
+    let content_type = headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+
+    let media_type = content_type.split(';').next().unwrap_or_default().trim();
+
+    let patch: P = if media_type.eq_ignore_ascii_case("application/json") {
+        serde_json::from_slice(&body).map_err(|err| {
+            if err.is_data() {
+                StatusCode::UNPROCESSABLE_ENTITY
+            } else {
+                StatusCode::BAD_REQUEST
+            }
+            .into_response()
+        })?
+    } else {
+        let document = PatchDocument::from_slice(content_type, &body)
+            .map_err(|err| patch_error_status(&err).into_response())?;
+        let data = Query::find_one::<R>(&db, condition.clone())
+            .await
+            .map_err(|err| db_error_status(&err).into_response())?
+            .ok_or_else(|| StatusCode::NOT_FOUND.into_response())?;
+        document
+            .apply::<P>(&data)
+            .map_err(|err| patch_error_status(&err).into_response())?
+    };
+    patch.validate().map_err(validation_errors_response)?;
+    let result = Mutation::update_one::<R, P, R::ActiveModel>(&db, condition, patch).await;
+
+    match result {
+        Ok(Some(data)) => Ok(Json(data.into())),
+        Ok(None) => Err(StatusCode::NOT_FOUND.into_response()),
+        Err(err) => Err(db_error_status(&err).into_response()),
+    }
+}
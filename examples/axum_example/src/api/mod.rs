@@ -1,3 +1,6 @@
+/// Maps database, patch & validation errors to responses.
+pub mod error;
+
 /// Request handlers.
 pub mod handlers;
 
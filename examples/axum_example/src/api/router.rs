@@ -1,25 +1,32 @@
-use axum::{routing::get, Router};
+use axum::{routing::get, Extension, Router};
 use sea_orm::DatabaseConnection;
+use sea_skipper::{BaseUrl, ResourceRoute};
 
 use super::handlers::{
     create::create,
     delete::delete_by_id,
     read::{get_by_id, get_collection},
+    update::update_by_id,
 };
 use crate::entity::cake;
 use crate::entity::prelude::*;
 
 /// Returns API ['Router'] that routes requests to handlers.
-pub fn router(db: DatabaseConnection) -> Router {
-    // TODO: Add update
+///
+/// Routes are built from each resource's [`RouteTemplate`](sea_skipper::RouteTemplate), and
+/// `Location` headers are prefixed with `base_url`.
+pub fn router(db: DatabaseConnection, base_url: BaseUrl) -> Router {
     Router::new()
         .route(
-            "/cakes",
+            &Cake::ROUTE.collection_route(),
             get(get_collection::<Cake>).post(create::<Cake, cake::NewModel>),
         )
         .route(
-            "/cakes/:id",
-            get(get_by_id::<Cake>).delete(delete_by_id::<Cake>),
+            &Cake::ROUTE.item_route(),
+            get(get_by_id::<Cake>)
+                .patch(update_by_id::<Cake, cake::PatchModel>)
+                .delete(delete_by_id::<Cake>),
         )
+        .layer(Extension(base_url))
         .with_state(db)
 }
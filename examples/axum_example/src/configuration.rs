@@ -11,6 +11,9 @@ pub struct Settings {
 pub struct ApiSettings {
     pub host: String,
     pub port: u16,
+    /// Base URL of absolute `Location` headers (relative paths are used if not set).
+    #[serde(default)]
+    pub base_url: Option<String>,
 }
 
 #[derive(serde::Deserialize)]
@@ -4,8 +4,8 @@
 //! can be re-used for different types of data.
 
 use sea_orm::{
-    ActiveModelBehavior, ActiveModelTrait, DbConn, DbErr, DeleteResult, EntityTrait,
-    IntoActiveModel, PrimaryKeyTrait,
+    ActiveModelBehavior, ActiveModelTrait, ActiveValue, Condition, DbConn, DbErr, DeleteResult,
+    EntityTrait, IntoActiveModel, Iterable, QueryFilter,
 };
 
 /// Creates, updates, and deletes entity data in the database.
@@ -23,14 +23,42 @@ impl Mutation {
         new_data.into_active_model().insert(db).await
     }
 
-    /// Deletes an entity from the database matching `id`.
-    pub async fn delete_by_id<E: EntityTrait, Id>(
+    /// Updates an entity in the database matching `condition` (e.g. its ID or an alternate key)
+    /// with the `Set` values of `patch`.
+    ///
+    /// Returns `None` if no entity matches `condition`.
+    pub async fn update_one<E, P, A>(
         db: &DbConn,
-        id: Id,
-    ) -> Result<DeleteResult, DbErr>
+        condition: Condition,
+        patch: P,
+    ) -> Result<Option<E::Model>, DbErr>
     where
-        Id: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType>,
+        E: EntityTrait,
+        E::Model: IntoActiveModel<A>,
+        P: IntoActiveModel<A>,
+        A: ActiveModelTrait<Entity = E> + ActiveModelBehavior + Send,
     {
-        <E as EntityTrait>::delete_by_id(id).exec(db).await
+        let Some(model) = <E as EntityTrait>::find().filter(condition).one(db).await? else {
+            return Ok(None);
+        };
+        let mut active_model = model.into_active_model();
+        let patch = patch.into_active_model();
+        for column in E::Column::iter() {
+            if let ActiveValue::Set(value) = patch.get(column) {
+                active_model.set(column, value);
+            }
+        }
+        active_model.update(db).await.map(Some)
+    }
+
+    /// Deletes an entity from the database matching `condition` (e.g. its ID or an alternate key).
+    pub async fn delete_one<E: EntityTrait>(
+        db: &DbConn,
+        condition: Condition,
+    ) -> Result<DeleteResult, DbErr> {
+        <E as EntityTrait>::delete_many()
+            .filter(condition)
+            .exec(db)
+            .await
     }
 }
@@ -2,7 +2,7 @@
 //!
 //! [`Query`] methods are generic over [`sea_orm`] entities (implementing [`EntityTrait`]) so they
 //! can be re-used for different types of data.
-use sea_orm::{DbConn, DbErr, EntityTrait, PrimaryKeyTrait};
+use sea_orm::{Condition, DbConn, DbErr, EntityTrait, QueryFilter};
 
 /// Reads entity data from the database.
 pub struct Query;
@@ -17,15 +17,14 @@ impl Query {
         <E as EntityTrait>::find().all(db).await
     }
 
-    /// Find an entity in the database by ID.
-    pub async fn find_by_id<E, Id>(
+    /// Find an entity in the database matching `condition` (e.g. its ID or an alternate key).
+    pub async fn find_one<E>(
         db: &DbConn,
-        id: Id,
+        condition: Condition,
     ) -> Result<Option<<E as EntityTrait>::Model>, DbErr>
     where
         E: EntityTrait,
-        Id: Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType>,
     {
-        <E as EntityTrait>::find_by_id(id).one(db).await
+        <E as EntityTrait>::find().filter(condition).one(db).await
     }
 }
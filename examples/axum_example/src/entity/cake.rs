@@ -1,20 +1,46 @@
 //! `SeaORM` Entity. Generated by sea-orm-codegen 0.11.3
 
 use sea_orm::entity::prelude::*;
-use sea_skipper::DeriveNewModel;
+use sea_skipper::{DeriveNewModel, DerivePatchModel, DeriveResource, DeriveViewModel};
 use serde::{Deserialize, Serialize};
 
 // `DeriveNewModel` derives a `NewModel` type similar to `Model` but without the primary key `id` field.
 // This may be used for create requests defining a new cake that hasn't been inserted into the
 // database yet.
+//
+// `DerivePatchModel` derives a `PatchModel` type with optional fields (except for `id`) for update
+// requests changing some of a cake's fields.
+//
+// `DeriveViewModel` derives a `ViewModel` type that is sent in responses (leaving out any
+// `#[skipper(hidden)]` fields), with a `Location` at the resource's path.
+//
+// `DeriveResource` implements `sea_skipper::Resource` for the cake `Entity` at `/cakes/{id}`,
+// responding with the `ViewModel`. A cake is also found at `/cakes/{slug}` (the `alternate_key`),
+// which its `Location` prefers when the cake has a slug.
 #[derive(
-    Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveNewModel, Serialize, Deserialize,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    DeriveEntityModel,
+    DeriveNewModel,
+    DerivePatchModel,
+    DeriveResource,
+    DeriveViewModel,
+    Serialize,
+    Deserialize,
 )]
 #[sea_orm(table_name = "cake")]
+#[skipper(resource(path = "/cakes/{id}", view = "ViewModel"))]
 pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i32,
+    #[sea_orm(unique)]
+    #[skipper(validate(length(min = 1, max = 100)))]
     pub name: String,
+    #[sea_orm(unique)]
+    #[skipper(alternate_key, validate(regex = "^[a-z][a-z0-9-]*$"))]
+    pub slug: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
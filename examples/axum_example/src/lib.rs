@@ -6,5 +6,3 @@ pub mod configuration;
 pub mod db;
 /// `sea-orm` database entities.
 pub mod entity;
-/// API resources.
-pub mod resource;
@@ -4,6 +4,7 @@ use std::net::SocketAddr;
 
 use anyhow::{Context, Result};
 use sea_orm::Database;
+use sea_skipper::BaseUrl;
 use secrecy::ExposeSecret;
 
 use axum_example::{api::router, configuration::get_configuration, db::migrate};
@@ -21,7 +22,7 @@ pub async fn main() -> Result<()> {
     // Run database migrations.
     migrate(&db).await?;
 
-    let app = router(db);
+    let app = router(db, BaseUrl::from_option(config.api.base_url));
 
     axum::Server::bind(&api_addr)
         .serve(app.into_make_service())
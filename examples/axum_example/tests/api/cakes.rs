@@ -1,8 +1,12 @@
 //! Integration tests for /cakes routes.
 
 use axum_example::entity::{cake, prelude::Cake};
-use reqwest::{header::LOCATION, StatusCode};
+use reqwest::{
+    header::{CONTENT_TYPE, LOCATION},
+    StatusCode,
+};
 use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, Set};
+use sea_skipper::BaseUrl;
 
 use crate::helpers::{http_client::Client, service::TestService};
 
@@ -18,6 +22,7 @@ async fn create_cake_with_model() {
         // The `id` field should be ignored.
         id: 987,
         name: "Pancake".to_string(),
+        slug: None,
     };
 
     let response = client.post("/cakes").json(&new_cake).send().await;
@@ -53,6 +58,7 @@ async fn create_cake_with_create_model() {
 
     let new_cake = cake::NewModel {
         name: "Pancake".to_string(),
+        slug: None,
     };
 
     let response = client.post("/cakes").json(&new_cake).send().await;
@@ -74,6 +80,91 @@ async fn create_cake_with_create_model() {
         headers.get(LOCATION).unwrap().to_str().unwrap(),
         format!("/cakes/{cake_id}")
     );
+
+    // The created cake is in the response body.
+    let created_cake: cake::Model = response.json().await;
+    assert_eq!(created_cake, new_cake.into_model(cake_id));
+}
+
+// Create a cake with an API service configured with a base URL.
+#[tokio::test]
+async fn create_cake_with_base_url() {
+    let api = TestService::with_base_url(BaseUrl::new("https://api.example.com/v1/")).await;
+    let db = api.database_connection();
+    let client = Client::new(api.api_url());
+
+    let new_cake = cake::NewModel {
+        name: "Pancake".to_string(),
+        slug: None,
+    };
+
+    let response = client.post("/cakes").json(&new_cake).send().await;
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let cake_id = Cake::find()
+        .filter(cake::Column::Name.eq("Pancake"))
+        .one(db)
+        .await
+        .unwrap()
+        .expect("No cake named 'Pancake' found in DB")
+        .id;
+
+    // Location header is an absolute URL.
+    assert_eq!(
+        response.headers().get(LOCATION).unwrap().to_str().unwrap(),
+        format!("https://api.example.com/v1/cakes/{cake_id}")
+    );
+}
+
+// Create a cake with a slug, which its `Location` prefers over the `id`.
+#[tokio::test]
+async fn create_cake_with_slug() {
+    let api = TestService::new().await;
+    let client = Client::new(api.api_url());
+
+    let new_cake = cake::NewModel {
+        name: "Chocolate Fudge".to_string(),
+        slug: Some("chocolate-fudge".to_string()),
+    };
+
+    let response = client.post("/cakes").json(&new_cake).send().await;
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert_eq!(
+        response.headers().get(LOCATION).unwrap().to_str().unwrap(),
+        "/cakes/chocolate-fudge"
+    );
+}
+
+// Create a cake that already exists in the database.
+#[tokio::test]
+async fn create_cake_already_exists() {
+    let api = TestService::new().await;
+    let db = api.database_connection();
+    let client = Client::new(api.api_url());
+
+    // Arrange: Prepare a cake in DB.
+    let cake = cake::ActiveModel {
+        name: Set("Pancake".to_string()),
+        ..Default::default()
+    }
+    .insert(db)
+    .await
+    .unwrap();
+    let cake_id = cake.id;
+
+    // A copy of the existing cake.
+    let new_cake = cake::NewModel::from(cake);
+
+    let response = client.post("/cakes").json(&new_cake).send().await;
+    let headers = response.headers();
+
+    // Redirected to the existing cake.
+    assert_eq!(response.status(), StatusCode::SEE_OTHER);
+    assert_eq!(
+        headers.get(LOCATION).unwrap().to_str().unwrap(),
+        format!("/cakes/{cake_id}")
+    );
 }
 
 #[tokio::test]
@@ -104,6 +195,25 @@ async fn get_cakes_collection() {
     }
 }
 
+// Create a cake with an invalid (empty) name.
+#[tokio::test]
+async fn create_cake_invalid() {
+    let api = TestService::new().await;
+    let db = api.database_connection();
+    let client = Client::new(api.api_url());
+
+    let new_cake = cake::NewModel::builder().name("").build();
+
+    let response = client.post("/cakes").json(&new_cake).send().await;
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let errors: serde_json::Value = response.json().await;
+    assert_eq!(errors[0]["path"], "name");
+    assert_eq!(errors[0]["code"], "length");
+    // No cake was created in DB.
+    assert!(Cake::find().all(db).await.unwrap().is_empty());
+}
+
 #[tokio::test]
 async fn get_cake_by_id_ok() {
     let api = TestService::new().await;
@@ -123,7 +233,7 @@ async fn get_cake_by_id_ok() {
     let response = client.get(&format!("/cakes/{cake_id}")).send().await;
 
     assert_eq!(response.status(), StatusCode::OK);
-    let cake: cake::Model = response.json().await;
+    let cake: cake::ViewModel = response.json().await;
     assert_eq!(cake.id, cake_id);
     assert_eq!(cake.name, "Coffee Cake");
 }
@@ -139,6 +249,326 @@ async fn get_cake_by_id_not_found() {
     assert_eq!(response.status(), StatusCode::NOT_FOUND);
 }
 
+#[tokio::test]
+async fn get_cake_by_slug_ok() {
+    let api = TestService::new().await;
+    let db = api.database_connection();
+    let client = Client::new(api.api_url());
+
+    // Arrange: Prepare a cake with a slug in DB.
+    let cake_id = cake::ActiveModel {
+        name: Set("Chocolate Fudge".to_string()),
+        slug: Set(Some("chocolate-fudge".to_string())),
+        ..Default::default()
+    }
+    .insert(db)
+    .await
+    .unwrap()
+    .id;
+
+    let response = client.get("/cakes/chocolate-fudge").send().await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let cake: cake::ViewModel = response.json().await;
+    assert_eq!(cake.id, cake_id);
+    assert_eq!(cake.name, "Chocolate Fudge");
+}
+
+#[tokio::test]
+async fn get_cake_by_slug_not_found() {
+    let api = TestService::new().await;
+    let client = Client::new(api.api_url());
+
+    // `abc` is not a cake ID, so it's looked up as a slug that no cake has.
+    let response = client.get("/cakes/abc").send().await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn update_cake_by_id_ok() {
+    let api = TestService::new().await;
+    let db = api.database_connection();
+    let client = Client::new(api.api_url());
+
+    // Arrange: Prepare a cake in DB.
+    let cake_id = cake::ActiveModel {
+        name: Set("Coffee Cake".to_string()),
+        ..Default::default()
+    }
+    .insert(db)
+    .await
+    .unwrap()
+    .id;
+
+    // Act: Rename the cake.
+    let patch = cake::PatchModel {
+        name: Some("Tea Cake".to_string()),
+        ..Default::default()
+    };
+    let response = client
+        .patch(&format!("/cakes/{cake_id}"))
+        .json(&patch)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let updated_cake: cake::Model = response.json().await;
+    assert_eq!(updated_cake.name, "Tea Cake");
+    // Cake is renamed in DB.
+    let cake = Cake::find_by_id(cake_id).one(db).await.unwrap().unwrap();
+    assert_eq!(cake.name, "Tea Cake");
+}
+
+#[tokio::test]
+async fn update_cake_by_slug_ok() {
+    let api = TestService::new().await;
+    let db = api.database_connection();
+    let client = Client::new(api.api_url());
+
+    // Arrange: Prepare a cake with a slug in DB.
+    let cake_id = cake::ActiveModel {
+        name: Set("Coffee Cake".to_string()),
+        slug: Set(Some("coffee-cake".to_string())),
+        ..Default::default()
+    }
+    .insert(db)
+    .await
+    .unwrap()
+    .id;
+
+    // Act: Rename the cake by its slug.
+    let response = client
+        .patch("/cakes/coffee-cake")
+        .json(&cake::PatchModel {
+            name: Some("Tea Cake".to_string()),
+            ..Default::default()
+        })
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    // Cake is renamed in DB.
+    let cake = Cake::find_by_id(cake_id).one(db).await.unwrap().unwrap();
+    assert_eq!(cake.name, "Tea Cake");
+}
+
+// An empty patch does not change the cake.
+#[tokio::test]
+async fn update_cake_by_id_empty_patch() {
+    let api = TestService::new().await;
+    let db = api.database_connection();
+    let client = Client::new(api.api_url());
+
+    // Arrange: Prepare a cake in DB.
+    let cake = cake::ActiveModel {
+        name: Set("Coffee Cake".to_string()),
+        ..Default::default()
+    }
+    .insert(db)
+    .await
+    .unwrap();
+
+    let response = client
+        .patch(&format!("/cakes/{}", cake.id))
+        .json(&cake::PatchModel::default())
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.json::<cake::Model>().await, cake);
+}
+
+#[tokio::test]
+async fn update_cake_by_id_not_found() {
+    let api = TestService::new().await;
+    let client = Client::new(api.api_url());
+
+    // No cake with ID 987 exists in the database.
+    let response = client
+        .patch("/cakes/987")
+        .json(&cake::PatchModel {
+            name: Some("Tea Cake".to_string()),
+            ..Default::default()
+        })
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+// Rename a cake with a JSON Merge Patch.
+#[tokio::test]
+async fn update_cake_by_id_merge_patch() {
+    let api = TestService::new().await;
+    let db = api.database_connection();
+    let client = Client::new(api.api_url());
+
+    // Arrange: Prepare a cake in DB.
+    let cake_id = cake::ActiveModel {
+        name: Set("Coffee Cake".to_string()),
+        ..Default::default()
+    }
+    .insert(db)
+    .await
+    .unwrap()
+    .id;
+
+    let response = client
+        .patch(&format!("/cakes/{cake_id}"))
+        .header(CONTENT_TYPE, "application/merge-patch+json")
+        .body(r#"{"name": "Tea Cake"}"#)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let cake = Cake::find_by_id(cake_id).one(db).await.unwrap().unwrap();
+    assert_eq!(cake.name, "Tea Cake");
+}
+
+// Rename a cake with a JSON Patch.
+#[tokio::test]
+async fn update_cake_by_id_json_patch() {
+    let api = TestService::new().await;
+    let db = api.database_connection();
+    let client = Client::new(api.api_url());
+
+    // Arrange: Prepare a cake in DB.
+    let cake_id = cake::ActiveModel {
+        name: Set("Coffee Cake".to_string()),
+        ..Default::default()
+    }
+    .insert(db)
+    .await
+    .unwrap()
+    .id;
+
+    let response = client
+        .patch(&format!("/cakes/{cake_id}"))
+        .header(CONTENT_TYPE, "application/json-patch+json")
+        .body(
+            r#"[
+                {"op": "test", "path": "/name", "value": "Coffee Cake"},
+                {"op": "replace", "path": "/name", "value": "Tea Cake"}
+            ]"#,
+        )
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let cake = Cake::find_by_id(cake_id).one(db).await.unwrap().unwrap();
+    assert_eq!(cake.name, "Tea Cake");
+}
+
+// Patch documents are validated like `PatchModel`s.
+#[tokio::test]
+async fn update_cake_by_id_merge_patch_invalid_name() {
+    let api = TestService::new().await;
+    let db = api.database_connection();
+    let client = Client::new(api.api_url());
+
+    // Arrange: Prepare a cake in DB.
+    let cake_id = cake::ActiveModel {
+        name: Set("Coffee Cake".to_string()),
+        ..Default::default()
+    }
+    .insert(db)
+    .await
+    .unwrap()
+    .id;
+
+    let response = client
+        .patch(&format!("/cakes/{cake_id}"))
+        .header(CONTENT_TYPE, "application/merge-patch+json")
+        .body(r#"{"name": ""}"#)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    // The cake is unchanged in DB.
+    let cake = Cake::find_by_id(cake_id).one(db).await.unwrap().unwrap();
+    assert_eq!(cake.name, "Coffee Cake");
+}
+
+// Patch documents changing the cake's `id` are rejected.
+#[tokio::test]
+async fn update_cake_by_id_patch_primary_key() {
+    let api = TestService::new().await;
+    let db = api.database_connection();
+    let client = Client::new(api.api_url());
+
+    // Arrange: Prepare a cake in DB.
+    let cake_id = cake::ActiveModel {
+        name: Set("Coffee Cake".to_string()),
+        ..Default::default()
+    }
+    .insert(db)
+    .await
+    .unwrap()
+    .id;
+
+    let response = client
+        .patch(&format!("/cakes/{cake_id}"))
+        .header(CONTENT_TYPE, "application/merge-patch+json")
+        .body(r#"{"id": 987, "name": "Tea Cake"}"#)
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    // The cake is unchanged in DB.
+    let cake = Cake::find_by_id(cake_id).one(db).await.unwrap().unwrap();
+    assert_eq!(cake.name, "Coffee Cake");
+}
+
+#[tokio::test]
+async fn update_cake_by_id_unsupported_media_type() {
+    let api = TestService::new().await;
+    let client = Client::new(api.api_url());
+
+    let response = client
+        .patch("/cakes/987")
+        .header(CONTENT_TYPE, "text/plain")
+        .body("Tea Cake")
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+}
+
+// Patches with invalid fields are rejected.
+#[tokio::test]
+async fn update_cake_by_id_invalid() {
+    let api = TestService::new().await;
+    let db = api.database_connection();
+    let client = Client::new(api.api_url());
+
+    // Arrange: Prepare a cake in DB.
+    let cake_id = cake::ActiveModel {
+        name: Set("Coffee Cake".to_string()),
+        ..Default::default()
+    }
+    .insert(db)
+    .await
+    .unwrap()
+    .id;
+
+    let response = client
+        .patch(&format!("/cakes/{cake_id}"))
+        .json(&cake::PatchModel {
+            name: Some(String::new()),
+            ..Default::default()
+        })
+        .send()
+        .await;
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let errors: serde_json::Value = response.json().await;
+    assert_eq!(errors[0]["path"], "name");
+    // The cake is unchanged in DB.
+    let cake = Cake::find_by_id(cake_id).one(db).await.unwrap().unwrap();
+    assert_eq!(cake.name, "Coffee Cake");
+}
+
 #[tokio::test]
 async fn delete_cake_by_id_ok() {
     let api = TestService::new().await;
@@ -163,6 +593,29 @@ async fn delete_cake_by_id_ok() {
     assert!(Cake::find_by_id(cake_id).one(db).await.unwrap().is_none());
 }
 
+#[tokio::test]
+async fn delete_cake_by_slug_ok() {
+    let api = TestService::new().await;
+    let db = api.database_connection();
+    let client = Client::new(api.api_url());
+
+    // Arrange: Prepare a cake with a slug in DB.
+    cake::ActiveModel {
+        name: Set("Chocolate Fudge".to_string()),
+        slug: Set(Some("chocolate-fudge".to_string())),
+        ..Default::default()
+    }
+    .insert(db)
+    .await
+    .unwrap();
+
+    let response = client.delete("/cakes/chocolate-fudge").send().await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    // Cake is no longer in DB.
+    assert!(Cake::find().all(db).await.unwrap().is_empty());
+}
+
 #[tokio::test]
 async fn delete_cake_by_id_not_found() {
     let api = TestService::new().await;
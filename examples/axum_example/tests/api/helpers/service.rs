@@ -5,6 +5,7 @@ use sea_orm::{prelude::Uuid, DatabaseConnection};
 use tower::make::Shared;
 
 use sea_orm::{ConnectionTrait, Database, Statement};
+use sea_skipper::BaseUrl;
 use secrecy::ExposeSecret;
 
 use axum_example::{api::router, configuration::get_configuration, db::migrate};
@@ -21,13 +22,19 @@ impl TestService {
     /// The API service is bound to http://127.0.0.1 on an unused port.
     /// A new database with a random name is created using the database service configured in `config.yml`.
     pub async fn new() -> TestService {
+        Self::with_base_url(BaseUrl::default()).await
+    }
+
+    /// Starts an API service (like [`TestService::new()`]) that prefixes `Location` headers with
+    /// `base_url`.
+    pub async fn with_base_url(base_url: BaseUrl) -> TestService {
         let listener = TcpListener::bind("127.0.0.1:0").expect("Could not bind ephemeral socket");
         let service_addr = listener.local_addr().unwrap();
         let service_url = format!("http://{}", service_addr);
         println!("API service is listening on {}", service_url);
 
         let db_conn = unique_database().await;
-        let router = router(db_conn.clone());
+        let router = router(db_conn.clone(), base_url);
 
         tokio::spawn(async move {
             let server = Server::from_tcp(listener)
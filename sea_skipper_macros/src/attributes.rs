@@ -1,17 +1,714 @@
+use proc_macro2::{Group, TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::{
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    Ident, Lit, Meta, NestedMeta, Path, Token,
+};
+
+/// An item of a `#[sea_orm(...)]` list: `key`, `key = value` or `key(...)`.
+///
+/// `sea_orm` attributes are shared with `sea_orm`'s derives, so the values of items that
+/// `sea_skipper` does not use are kept as tokens & ignored.
+struct SeaOrmItem {
+    key: Ident,
+    value: Option<TokenStream>,
+}
+
+impl Parse for SeaOrmItem {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key = input.call(Ident::parse_any)?;
+        let value = if input.peek(Token![=]) {
+            let eq = input.parse::<Token![=]>()?;
+            let mut value = TokenStream::new();
+            while !input.is_empty() && !input.peek(Token![,]) {
+                value.extend([input.parse::<TokenTree>()?]);
+            }
+            if value.is_empty() {
+                return Err(syn::Error::new_spanned(
+                    eq,
+                    format!("expected a value for `{key}`"),
+                ));
+            }
+            Some(value)
+        } else {
+            // Skip the arguments of other `sea_orm` items (e.g. `key(...)`).
+            if input.peek(syn::token::Paren) {
+                input.parse::<Group>()?;
+            }
+            None
+        };
+        Ok(Self { key, value })
+    }
+}
+
+impl SeaOrmItem {
+    /// Parses the value of `key = value` as `T`, from the contents of a string literal (e.g.
+    /// `"super::Entity"`) or the value itself (e.g. `Entity`).
+    fn parse_value<T: Parse>(&self) -> syn::Result<T> {
+        let key = &self.key;
+        let Some(value) = &self.value else {
+            return Err(syn::Error::new_spanned(
+                key,
+                format!("expected `{key} = \"...\"`"),
+            ));
+        };
+        let parsed = match syn::parse2::<Lit>(value.clone()) {
+            Ok(lit @ Lit::Str(_)) => parse_lit_str(&lit),
+            _ => syn::parse2(value.clone()),
+        };
+        parsed.map_err(|err| syn::Error::new_spanned(value, format!("invalid `{key}`: {err}")))
+    }
+}
+
+/// Names of the options given in `skipper` attributes, to reject duplicate & conflicting options.
+#[derive(Default)]
+struct SeenOptions(Vec<Path>);
+
+impl SeenOptions {
+    /// Records the option `path`, or returns an error if it was already given.
+    fn insert(&mut self, path: &Path) -> syn::Result<()> {
+        let name = path.to_token_stream().to_string();
+        if self
+            .0
+            .iter()
+            .any(|seen| seen.to_token_stream().to_string() == name)
+        {
+            return Err(syn::Error::new_spanned(
+                path,
+                format!("duplicate `skipper` attribute `{name}`"),
+            ));
+        }
+        self.0.push(path.clone());
+        Ok(())
+    }
+
+    /// Returns an error with `message` (pointing at the last of the two options) if both options
+    /// `a` & `b` were given.
+    fn check_conflict(&self, a: &str, b: &str, message: &str) -> syn::Result<()> {
+        let position = |name: &str| self.0.iter().position(|path| path.is_ident(name));
+        match (position(a), position(b)) {
+            (Some(i), Some(j)) => Err(syn::Error::new_spanned(&self.0[i.max(j)], message)),
+            _ => Ok(()),
+        }
+    }
+}
+
 pub mod derive_attr {
-    use bae::FromAttributes;
+    use quote::ToTokens;
+    use syn::{
+        punctuated::Punctuated, token::Comma, Attribute, Ident, Lit, LitStr, Meta, NestedMeta,
+        Path, Type, Visibility,
+    };
+
+    use super::{attr_metas, nested_metas, parse_lit_str, SeaOrmItem, SeenOptions};
+    use crate::derives::util::{not_sea_orm_attr, not_skipper_attr};
+
+    /// `#[sea_orm(...)]` attributes on a Model that are relevant to `sea_skipper` derives.
+    #[derive(Default)]
+    pub struct SeaOrm {
+        /// `#[sea_orm(active_model = "...")]` names the `ActiveModel` (defaults to `ActiveModel`).
+        pub active_model: Option<Ident>,
+        /// `#[sea_orm(entity = "...")]` is the path of the `Entity` (defaults to `Entity`).
+        pub entity: Option<Path>,
+        /// `#[sea_orm(model = "...")]` on an `Entity` is the path of its `Model` (defaults to
+        /// `Model`).
+        pub model: Option<Path>,
+    }
+
+    impl SeaOrm {
+        /// Parses `#[sea_orm(...)]` attributes in `attrs` (other attributes & `sea_orm` items are
+        /// ignored).
+        pub fn from_attributes(attrs: &[Attribute]) -> syn::Result<Self> {
+            let mut sea_orm = Self::default();
+            for attr in attrs {
+                if not_sea_orm_attr(attr) {
+                    continue;
+                }
+                let items =
+                    attr.parse_args_with(Punctuated::<SeaOrmItem, Comma>::parse_terminated)?;
+                for item in items {
+                    if item.key == "active_model" {
+                        sea_orm.active_model = Some(item.parse_value()?);
+                    } else if item.key == "entity" {
+                        sea_orm.entity = Some(item.parse_value()?);
+                    } else if item.key == "model" {
+                        sea_orm.model = Some(item.parse_value()?);
+                    }
+                }
+            }
+            Ok(sea_orm)
+        }
+    }
+
+    /// `#[skipper(...)]` attributes on a Model.
+    #[derive(Default)]
+    pub struct Skipper {
+        /// `#[skipper(new_model(...))]` configures the `NewModel` derived by `DeriveNewModel`.
+        pub new_model: DerivedStruct,
+        /// `#[skipper(new_model_attr(...))]` attributes added to the `NewModel` struct.
+        pub new_model_attrs: Vec<Meta>,
+        /// `#[skipper(patch_model(...))]` configures the `PatchModel` derived by `DerivePatchModel`.
+        pub patch_model: DerivedStruct,
+        /// `#[skipper(patch_model_attr(...))]` attributes added to the `PatchModel` struct.
+        pub patch_model_attrs: Vec<Meta>,
+        /// `#[skipper(view_model(...))]` configures the `ViewModel` derived by `DeriveViewModel`.
+        pub view_model: DerivedStruct,
+        /// `#[skipper(view_model_attr(...))]` attributes added to the `ViewModel` struct.
+        pub view_model_attrs: Vec<Meta>,
+        /// `#[skipper(location = "...")]` path template of the `Location` of a `ViewModel` (e.g.
+        /// `"/cakes/{id}"`).
+        pub location: Option<LitStr>,
+        /// `#[skipper(resource(...))]` configures the `Resource` derived by `DeriveResource`.
+        pub resource: ResourceAttr,
+        /// `#[skipper(crate = "...")]` is the path of the `sea_skipper` crate in generated code.
+        pub krate: Option<Path>,
+        /// `#[skipper(sea_orm_crate = "...")]` is the path of the `sea_orm` crate in generated code.
+        pub sea_orm_crate: Option<Path>,
+    }
+
+    /// `#[skipper(new_model(name = "...", vis = "...", derive(...), default_derives = false))]`,
+    /// `#[skipper(patch_model(...))]` or `#[skipper(view_model(...))]`
+    #[derive(Default)]
+    pub struct DerivedStruct {
+        /// Name of the derived struct (defaults to `NewModel`, `PatchModel` or `ViewModel`).
+        pub name: Option<Ident>,
+        /// Visibility of the derived struct, its fields and methods (defaults to `pub`).
+        pub vis: Option<Visibility>,
+        /// `derive(...)` paths added to the derived struct's derives.
+        pub derives: Vec<Path>,
+        /// `default_derives = false` replaces the default derives with `derives`.
+        pub replace_derives: bool,
+    }
+
+    /// `#[skipper(resource(path = "...", view = "..."))]`
+    #[derive(Default)]
+    pub struct ResourceAttr {
+        /// `path = "..."` path template of the `Location` of the resource (e.g. `"/cakes/{id}"`).
+        pub path: Option<LitStr>,
+        /// `view = "..."` is the `View` type of the resource (defaults to its `Model`).
+        pub view: Option<Type>,
+    }
+
+    impl ResourceAttr {
+        fn parse(&mut self, meta: &Meta) -> syn::Result<()> {
+            let name = meta.path().to_token_stream();
+            for nested in nested_metas(meta)? {
+                match &nested {
+                    NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("path") => {
+                        if self.path.is_some() {
+                            return Err(duplicate(&name, &nv.path));
+                        }
+                        match &nv.lit {
+                            Lit::Str(litstr) => self.path = Some(litstr.clone()),
+                            lit => {
+                                return Err(syn::Error::new_spanned(
+                                    lit,
+                                    "expected a string literal",
+                                ))
+                            }
+                        }
+                    }
+                    NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("view") => {
+                        if self.view.is_some() {
+                            return Err(duplicate(&name, &nv.path));
+                        }
+                        self.view = Some(parse_lit_str(&nv.lit)?);
+                    }
+                    NestedMeta::Meta(meta) => {
+                        let option = meta.path().to_token_stream();
+                        return Err(syn::Error::new_spanned(
+                            meta.path(),
+                            format!("unknown `{name}` attribute `{option}`"),
+                        ));
+                    }
+                    NestedMeta::Lit(lit) => {
+                        return Err(syn::Error::new_spanned(
+                            lit,
+                            format!("expected a `{name}` attribute"),
+                        ))
+                    }
+                }
+            }
+            Ok(())
+        }
+    }
+
+    impl Skipper {
+        /// Parses `#[skipper(...)]` attributes in `attrs` (other attributes are ignored).
+        pub fn from_attributes(attrs: &[Attribute]) -> syn::Result<Self> {
+            let mut skipper = Self::default();
+            let mut seen = SeenOptions::default();
+            for attr in attrs {
+                if not_skipper_attr(attr) {
+                    continue;
+                }
+                for nested in nested_metas(&attr.parse_meta()?)? {
+                    if let NestedMeta::Meta(Meta::NameValue(nv)) = &nested {
+                        seen.insert(&nv.path)?;
+                    }
+                    match &nested {
+                        NestedMeta::Meta(meta) if meta.path().is_ident("new_model") => {
+                            skipper.new_model.parse(meta)?;
+                        }
+                        NestedMeta::Meta(meta) if meta.path().is_ident("new_model_attr") => {
+                            skipper.new_model_attrs.extend(attr_metas(meta)?);
+                        }
+                        NestedMeta::Meta(meta) if meta.path().is_ident("patch_model") => {
+                            skipper.patch_model.parse(meta)?;
+                        }
+                        NestedMeta::Meta(meta) if meta.path().is_ident("patch_model_attr") => {
+                            skipper.patch_model_attrs.extend(attr_metas(meta)?);
+                        }
+                        NestedMeta::Meta(meta) if meta.path().is_ident("view_model") => {
+                            skipper.view_model.parse(meta)?;
+                        }
+                        NestedMeta::Meta(meta) if meta.path().is_ident("view_model_attr") => {
+                            skipper.view_model_attrs.extend(attr_metas(meta)?);
+                        }
+                        NestedMeta::Meta(meta) if meta.path().is_ident("resource") => {
+                            skipper.resource.parse(meta)?;
+                        }
+                        NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("crate") => {
+                            skipper.krate = Some(parse_lit_str(&nv.lit)?);
+                        }
+                        NestedMeta::Meta(Meta::NameValue(nv))
+                            if nv.path.is_ident("sea_orm_crate") =>
+                        {
+                            skipper.sea_orm_crate = Some(parse_lit_str(&nv.lit)?);
+                        }
+                        NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("location") => {
+                            match &nv.lit {
+                                Lit::Str(litstr) => skipper.location = Some(litstr.clone()),
+                                lit => {
+                                    return Err(syn::Error::new_spanned(
+                                        lit,
+                                        "expected a string literal",
+                                    ))
+                                }
+                            }
+                        }
+                        NestedMeta::Meta(meta) => {
+                            let name = meta.path().to_token_stream();
+                            return Err(syn::Error::new_spanned(
+                                meta.path(),
+                                format!("unknown `skipper` attribute `{name}`"),
+                            ));
+                        }
+                        NestedMeta::Lit(lit) => {
+                            return Err(syn::Error::new_spanned(
+                                lit,
+                                "expected a `skipper` attribute",
+                            ))
+                        }
+                    }
+                }
+            }
+            Ok(skipper)
+        }
+    }
+
+    impl DerivedStruct {
+        fn parse(&mut self, meta: &Meta) -> syn::Result<()> {
+            let name = meta.path().to_token_stream();
+            for nested in nested_metas(meta)? {
+                match &nested {
+                    NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("name") => {
+                        if self.name.is_some() {
+                            return Err(duplicate(&name, &nv.path));
+                        }
+                        self.name = Some(parse_lit_str(&nv.lit)?);
+                    }
+                    NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("vis") => {
+                        if self.vis.is_some() {
+                            return Err(duplicate(&name, &nv.path));
+                        }
+                        self.vis = Some(parse_lit_str(&nv.lit)?);
+                    }
+                    NestedMeta::Meta(meta @ Meta::List(list)) if list.path.is_ident("derive") => {
+                        for nested in nested_metas(meta)? {
+                            match nested {
+                                NestedMeta::Meta(Meta::Path(path)) => self.derives.push(path),
+                                nested => {
+                                    return Err(syn::Error::new_spanned(
+                                        nested,
+                                        "expected a derive macro path",
+                                    ))
+                                }
+                            }
+                        }
+                    }
+                    NestedMeta::Meta(Meta::NameValue(nv))
+                        if nv.path.is_ident("default_derives") =>
+                    {
+                        match &nv.lit {
+                            Lit::Bool(litbool) => self.replace_derives = !litbool.value,
+                            lit => {
+                                return Err(syn::Error::new_spanned(
+                                    lit,
+                                    "expected `true` or `false`",
+                                ))
+                            }
+                        }
+                    }
+                    NestedMeta::Meta(meta) => {
+                        let option = meta.path().to_token_stream();
+                        return Err(syn::Error::new_spanned(
+                            meta.path(),
+                            format!("unknown `{name}` attribute `{option}`"),
+                        ));
+                    }
+                    NestedMeta::Lit(lit) => {
+                        return Err(syn::Error::new_spanned(
+                            lit,
+                            format!("expected a `{name}` attribute"),
+                        ))
+                    }
+                }
+            }
+            Ok(())
+        }
+    }
+
+    /// Returns an error for the option `path` given twice in `name(...)` (e.g. `new_model`).
+    fn duplicate(name: &proc_macro2::TokenStream, path: &Path) -> syn::Error {
+        let option = path.to_token_stream();
+        syn::Error::new_spanned(path, format!("duplicate `{name}` attribute `{option}`"))
+    }
+}
+
+pub mod field_attr {
+    use quote::ToTokens;
+    use syn::{
+        punctuated::Punctuated, token::Comma, Expr, Field, Ident, Lit, LitStr, Meta, NestedMeta,
+        Path,
+    };
+
+    use super::{attr_metas, nested_metas, parse_lit_str, SeaOrmItem, SeenOptions};
+    use crate::derives::util::{not_sea_orm_attr, not_skipper_attr};
 
-    /// Attributes for Models and ActiveModels
-    #[derive(Default, FromAttributes)]
+    /// `#[sea_orm(...)]` attributes on a Model's field that are relevant to `sea_skipper` derives.
+    #[derive(Default)]
     pub struct SeaOrm {
-        pub active_model: Option<syn::Ident>,
-        pub column: Option<syn::Ident>,
-        pub entity: Option<syn::Ident>,
-        pub model: Option<syn::Ident>,
-        pub primary_key: Option<syn::Ident>,
-        pub relation: Option<syn::Ident>,
-        pub schema_name: Option<syn::Lit>,
-        pub table_name: Option<syn::Lit>,
-        pub table_iden: Option<()>,
+        /// `#[sea_orm(enum_name = "...")]` determines the Column variant ident (if present).
+        pub enum_name: Option<Ident>,
+        /// `#[sea_orm(primary_key)]`
+        pub primary_key: bool,
+        /// `#[sea_orm(unique)]`
+        pub unique: bool,
+    }
+
+    impl SeaOrm {
+        /// Parses `#[sea_orm(...)]` attributes on `field` (other attributes & `sea_orm` items are
+        /// ignored).
+        pub fn from_field(field: &Field) -> syn::Result<Self> {
+            let mut sea_orm = Self::default();
+            for attr in field.attrs.iter() {
+                if not_sea_orm_attr(attr) {
+                    continue;
+                }
+                let items =
+                    attr.parse_args_with(Punctuated::<SeaOrmItem, Comma>::parse_terminated)?;
+                for item in items {
+                    if item.key == "enum_name" {
+                        sea_orm.enum_name = Some(item.parse_value()?);
+                    } else if item.value.is_none() && item.key == "primary_key" {
+                        sea_orm.primary_key = true;
+                    } else if item.value.is_none() && item.key == "unique" {
+                        sea_orm.unique = true;
+                    }
+                }
+            }
+            Ok(sea_orm)
+        }
+    }
+
+    /// `#[skipper(...)]` attributes on a Model's field.
+    pub struct Skipper {
+        /// `#[skipper(condition = false)]` excludes the field from `ModelCondition` conditions.
+        pub condition: bool,
+        /// `#[skipper(skip_new)]` excludes the field from the `NewModel` derived by `DeriveNewModel`.
+        pub skip_new: bool,
+        /// `#[skipper(skip_new = "...")]` excludes the field from the `NewModel` & sets its value in
+        /// the `ActiveModel` to the given expression.
+        pub skip_new_default: Option<Expr>,
+        /// `#[skipper(unique_group = "...")]` names a composite unique key this field is part of.
+        pub unique_group: Option<String>,
+        /// `#[skipper(default)]` or `#[skipper(default = "...")]` lets the field be omitted from the
+        /// `NewModel` builder (its value is then `Default::default()` or the given expression).
+        pub default: Option<Expr>,
+        /// `#[skipper(new_model_attr(...))]` attributes added to the field on the `NewModel`.
+        pub new_model_attrs: Vec<Meta>,
+        /// `#[skipper(patch_model_attr(...))]` attributes added to the field on the `PatchModel`.
+        pub patch_model_attrs: Vec<Meta>,
+        /// `#[skipper(hidden)]` excludes the field from the `ViewModel` derived by `DeriveViewModel`.
+        pub hidden: bool,
+        /// `#[skipper(read_only)]` excludes the field from the `NewModel` & `PatchModel` (it's only
+        /// returned, never accepted).
+        pub read_only: bool,
+        /// `#[skipper(write_only)]` excludes the field from the `ViewModel` (it's only accepted,
+        /// never returned).
+        pub write_only: bool,
+        /// `#[skipper(view_rename = "...")]` renames the field on the `ViewModel`.
+        pub view_rename: Option<Ident>,
+        /// `#[skipper(view_model_attr(...))]` attributes added to the field on the `ViewModel`.
+        pub view_model_attrs: Vec<Meta>,
+        /// `#[skipper(validate(...))]` validators of the field's value.
+        pub validators: Vec<Validator>,
+        /// `#[skipper(alternate_key)]` identifies the resource in URL paths as an alternative to
+        /// the primary key (e.g. a slug).
+        pub alternate_key: bool,
+    }
+
+    /// Pairs of field options that can't be used together & the error message when they are.
+    const CONFLICTS: &[(&str, &str, &str)] = &[
+        (
+            "read_only",
+            "write_only",
+            "a field can't be both `read_only` and `write_only`",
+        ),
+        (
+            "read_only",
+            "default",
+            "a `read_only` field is never accepted, so it can't have a `default`",
+        ),
+        (
+            "hidden",
+            "view_rename",
+            "a `hidden` field is not in the `ViewModel`, so it can't be renamed",
+        ),
+        (
+            "write_only",
+            "view_rename",
+            "a `write_only` field is not in the `ViewModel`, so it can't be renamed",
+        ),
+    ];
+
+    /// A validator in `#[skipper(validate(...))]`.
+    pub enum Validator {
+        /// `length(min = ..., max = ...)`
+        Length {
+            min: Option<Expr>,
+            max: Option<Expr>,
+        },
+        /// `range(min = ..., max = ...)`
+        Range {
+            min: Option<Expr>,
+            max: Option<Expr>,
+        },
+        /// `regex = "..."`
+        Regex(LitStr),
+        /// `email`
+        Email,
+        /// `custom = "path::to::function"`
+        Custom(Path),
+    }
+
+    impl Validator {
+        /// Parses the validators listed in `validate(...)`.
+        fn parse_list(meta: &Meta) -> syn::Result<Vec<Self>> {
+            nested_metas(meta)?
+                .iter()
+                .map(|nested| match nested {
+                    NestedMeta::Meta(meta @ Meta::List(list)) if list.path.is_ident("length") => {
+                        let (min, max) = Self::parse_bounds(meta)?;
+                        Ok(Self::Length { min, max })
+                    }
+                    NestedMeta::Meta(meta @ Meta::List(list)) if list.path.is_ident("range") => {
+                        let (min, max) = Self::parse_bounds(meta)?;
+                        Ok(Self::Range { min, max })
+                    }
+                    NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("regex") => {
+                        match &nv.lit {
+                            // Patterns are compiled (including `regex`'s size limits) so invalid
+                            // patterns fail at compile time instead of when validating.
+                            Lit::Str(litstr) => match regex::Regex::new(&litstr.value()) {
+                                Ok(_) => Ok(Self::Regex(litstr.clone())),
+                                Err(err) => Err(syn::Error::new_spanned(litstr, err.to_string())),
+                            },
+                            lit => Err(syn::Error::new_spanned(lit, "expected a string literal")),
+                        }
+                    }
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("email") => Ok(Self::Email),
+                    NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("custom") => {
+                        Ok(Self::Custom(parse_lit_str(&nv.lit)?))
+                    }
+                    _ => Err(syn::Error::new_spanned(nested, "unknown validator")),
+                })
+                .collect()
+        }
+
+        /// Parses `min = ...` and `max = ...` of `length(...)` or `range(...)`.
+        fn parse_bounds(meta: &Meta) -> syn::Result<(Option<Expr>, Option<Expr>)> {
+            let (mut min, mut max) = (None, None);
+            for nested in nested_metas(meta)? {
+                let NestedMeta::Meta(Meta::NameValue(nv)) = &nested else {
+                    return Err(syn::Error::new_spanned(
+                        nested,
+                        "expected `min = ...` or `max = ...`",
+                    ));
+                };
+                // String literals contain an expression (e.g. `min = "MIN_PRICE"`).
+                let bound: Expr = match &nv.lit {
+                    Lit::Str(_) => parse_lit_str(&nv.lit)?,
+                    lit => syn::parse_quote!(#lit),
+                };
+                if nv.path.is_ident("min") {
+                    min = Some(bound);
+                } else if nv.path.is_ident("max") {
+                    max = Some(bound);
+                } else {
+                    return Err(syn::Error::new_spanned(&nv.path, "expected `min` or `max`"));
+                }
+            }
+            if min.is_none() && max.is_none() {
+                return Err(syn::Error::new_spanned(meta, "expected `min` and/or `max`"));
+            }
+            Ok((min, max))
+        }
+    }
+
+    impl Default for Skipper {
+        fn default() -> Self {
+            Self {
+                condition: true,
+                skip_new: false,
+                skip_new_default: None,
+                unique_group: None,
+                default: None,
+                new_model_attrs: Vec::new(),
+                patch_model_attrs: Vec::new(),
+                hidden: false,
+                read_only: false,
+                write_only: false,
+                view_rename: None,
+                view_model_attrs: Vec::new(),
+                validators: Vec::new(),
+                alternate_key: false,
+            }
+        }
+    }
+
+    impl Skipper {
+        /// Parses `#[skipper(...)]` attributes on `field` (other attributes are ignored).
+        pub fn from_field(field: &Field) -> syn::Result<Self> {
+            let mut skipper = Self::default();
+            let mut seen = SeenOptions::default();
+            for attr in field.attrs.iter() {
+                if not_skipper_attr(attr) {
+                    continue;
+                }
+                let list = attr.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)?;
+                for meta in list.iter() {
+                    if !matches!(meta, Meta::List(_)) {
+                        seen.insert(meta.path())?;
+                    }
+                    match meta {
+                        Meta::NameValue(nv) if nv.path.is_ident("condition") => {
+                            if let Lit::Bool(litbool) = &nv.lit {
+                                skipper.condition = litbool.value;
+                            } else {
+                                return Err(syn::Error::new_spanned(
+                                    &nv.lit,
+                                    "expected `true` or `false`",
+                                ));
+                            }
+                        }
+                        Meta::Path(p) if p.is_ident("skip_new") => {
+                            skipper.skip_new = true;
+                        }
+                        Meta::NameValue(nv) if nv.path.is_ident("skip_new") => {
+                            skipper.skip_new = true;
+                            skipper.skip_new_default = Some(parse_lit_str(&nv.lit)?);
+                        }
+                        Meta::Path(p) if p.is_ident("default") => {
+                            skipper.default =
+                                Some(syn::parse_quote!(::std::default::Default::default()));
+                        }
+                        Meta::NameValue(nv) if nv.path.is_ident("default") => {
+                            skipper.default = Some(parse_lit_str(&nv.lit)?);
+                        }
+                        Meta::List(_) if meta.path().is_ident("new_model_attr") => {
+                            skipper.new_model_attrs.extend(attr_metas(meta)?);
+                        }
+                        Meta::List(_) if meta.path().is_ident("patch_model_attr") => {
+                            skipper.patch_model_attrs.extend(attr_metas(meta)?);
+                        }
+                        Meta::Path(p) if p.is_ident("hidden") => {
+                            skipper.hidden = true;
+                        }
+                        Meta::Path(p) if p.is_ident("read_only") => {
+                            skipper.read_only = true;
+                        }
+                        Meta::Path(p) if p.is_ident("write_only") => {
+                            skipper.write_only = true;
+                        }
+                        Meta::Path(p) if p.is_ident("alternate_key") => {
+                            skipper.alternate_key = true;
+                        }
+                        Meta::NameValue(nv) if nv.path.is_ident("view_rename") => {
+                            skipper.view_rename = Some(parse_lit_str(&nv.lit)?);
+                        }
+                        Meta::List(_) if meta.path().is_ident("view_model_attr") => {
+                            skipper.view_model_attrs.extend(attr_metas(meta)?);
+                        }
+                        Meta::List(_) if meta.path().is_ident("validate") => {
+                            skipper.validators.extend(Validator::parse_list(meta)?);
+                        }
+                        Meta::NameValue(nv) if nv.path.is_ident("unique_group") => {
+                            if let Lit::Str(litstr) = &nv.lit {
+                                skipper.unique_group = Some(litstr.value());
+                            } else {
+                                return Err(syn::Error::new_spanned(
+                                    &nv.lit,
+                                    "expected a string literal",
+                                ));
+                            }
+                        }
+                        _ => {
+                            let name = meta.path().to_token_stream();
+                            return Err(syn::Error::new_spanned(
+                                meta.path(),
+                                format!("unknown `skipper` field attribute `{name}`"),
+                            ));
+                        }
+                    }
+                }
+            }
+            for (a, b, message) in CONFLICTS {
+                seen.check_conflict(a, b, message)?;
+            }
+            Ok(skipper)
+        }
+    }
+}
+
+/// Returns the nested metas of a list (e.g. `a, b = "c"` in `skipper(a, b = "c")`).
+fn nested_metas(meta: &Meta) -> syn::Result<Vec<NestedMeta>> {
+    match meta {
+        Meta::List(list) => Ok(list.nested.iter().cloned().collect()),
+        _ => Err(syn::Error::new_spanned(
+            meta,
+            "expected a list of attributes",
+        )),
+    }
+}
+
+/// Returns the attributes listed in `new_model_attr(...)` (e.g. `serde(rename = "...")`).
+fn attr_metas(meta: &Meta) -> syn::Result<Vec<Meta>> {
+    nested_metas(meta)?
+        .into_iter()
+        .map(|nested| match nested {
+            NestedMeta::Meta(meta) => Ok(meta),
+            NestedMeta::Lit(lit) => Err(syn::Error::new_spanned(lit, "expected an attribute")),
+        })
+        .collect()
+}
+
+/// Parses the contents of a string literal (e.g. `"pub(crate)"`) as `T`.
+fn parse_lit_str<T: syn::parse::Parse>(lit: &Lit) -> syn::Result<T> {
+    match lit {
+        Lit::Str(litstr) => litstr.parse(),
+        _ => Err(syn::Error::new_spanned(lit, "expected a string literal")),
     }
 }
@@ -1,6 +1,13 @@
 mod model_condition;
 mod new_model;
-mod util;
+mod patch_model;
+mod resource;
+pub(crate) mod util;
+mod validate;
+mod view_model;
 
 pub use model_condition::*;
 pub use new_model::*;
+pub use patch_model::*;
+pub use resource::*;
+pub use view_model::*;
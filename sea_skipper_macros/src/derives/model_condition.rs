@@ -1,93 +1,219 @@
-use heck::ToUpperCamelCase;
 use proc_macro2::TokenStream;
-use quote::{format_ident, quote, quote_spanned};
-use syn::{punctuated::Punctuated, token::Comma, Ident, Lit, Meta};
+use quote::quote;
+use syn::{Generics, Ident, Path};
 
-use super::util::{escape_rust_keyword, not_sea_orm_attr, trim_starting_raw_identifier};
+use super::util::{column_ident, is_option_type, named_fields, CratePaths};
+use crate::attributes::{derive_attr, field_attr};
 
-enum Error {
-    InputNotStruct,
+/// A field of the input struct that is compared in generated conditions.
+struct ConditionField {
+    column_ident: Ident,
+    field_ident: Ident,
+    /// Whether the field is an `Option`.
+    optional: bool,
 }
 
 pub struct DeriveModelCondition {
-    column_idents: Vec<Ident>,
+    crate_paths: CratePaths,
+    /// Path of the entity, from `#[sea_orm(entity = "...")]` (defaults to `Entity`).
+    entity_path: Path,
+    fields: Vec<ConditionField>,
+    generics: Generics,
     ident: Ident,
+    /// Unique keys as lists of column idents, from `#[sea_orm(unique)]` (single column keys) and
+    /// `#[skipper(unique_group = "...")]` (composite keys) in order of appearance.
+    unique_keys: Vec<Vec<Ident>>,
 }
 
 impl DeriveModelCondition {
-    fn new(input: syn::DeriveInput) -> Result<Self, Error> {
-        let fields = match input.data {
-            syn::Data::Struct(syn::DataStruct {
-                fields: syn::Fields::Named(syn::FieldsNamed { named, .. }),
-                ..
-            }) => named,
-            _ => return Err(Error::InputNotStruct),
-        };
+    fn new(input: syn::DeriveInput) -> syn::Result<Self> {
+        let fields = named_fields(&input.ident, input.data, "DeriveModelCondition")?;
+
+        let sea_attr = derive_attr::SeaOrm::from_attributes(&input.attrs)?;
+        let entity_path = sea_attr.entity.unwrap_or_else(|| syn::parse_quote!(Entity));
+        let skipper_attr = derive_attr::Skipper::from_attributes(&input.attrs)?;
+        let crate_paths = CratePaths::new(&skipper_attr);
+        let generics = input.generics;
 
         let ident = input.ident;
 
-        let column_idents = fields
-            .iter()
-            .map(|field| {
-                let ident = field.ident.as_ref().unwrap().to_string();
-                let ident = trim_starting_raw_identifier(ident).to_upper_camel_case();
-                let ident = escape_rust_keyword(ident);
-                let mut ident = format_ident!("{}", &ident);
-                for attr in field.attrs.iter() {
-                    if not_sea_orm_attr(attr) {
-                        continue;
-                    }
-                    if let Ok(list) =
-                        attr.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)
-                    {
-                        for meta in list.iter() {
-                            if let Meta::NameValue(nv) = meta {
-                                if let Some(name) = nv.path.get_ident() {
-                                    if name == "enum_name" {
-                                        if let Lit::Str(litstr) = &nv.lit {
-                                            ident = syn::parse_str(&litstr.value()).unwrap();
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
+        let mut condition_fields = Vec::new();
+        let mut unique_keys: Vec<Vec<Ident>> = Vec::new();
+        // Names of `unique_group`s and their index in `unique_keys`.
+        let mut unique_groups: Vec<(String, usize)> = Vec::new();
+
+        for field in fields {
+            let field_ident = field.ident.clone().unwrap();
+            let sea_attr = field_attr::SeaOrm::from_field(&field)?;
+            let skipper_attr = field_attr::Skipper::from_field(&field)?;
+
+            let column_ident = sea_attr
+                .enum_name
+                .unwrap_or_else(|| column_ident(&field_ident));
+
+            // Fields with `#[skipper(condition = false)]` are left out of all conditions.
+            if !skipper_attr.condition {
+                if sea_attr.unique || skipper_attr.unique_group.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        &field_ident,
+                        "`#[skipper(condition = false)]` cannot be used on a unique key field",
+                    ));
+                }
+                continue;
+            }
+
+            if sea_attr.unique {
+                unique_keys.push(vec![column_ident.clone()]);
+            }
+            if let Some(group) = skipper_attr.unique_group {
+                if let Some((_, i)) = unique_groups.iter().find(|(g, _)| g == &group) {
+                    unique_keys[*i].push(column_ident.clone());
+                } else {
+                    unique_groups.push((group, unique_keys.len()));
+                    unique_keys.push(vec![column_ident.clone()]);
                 }
-                ident
-            })
-            .collect();
+            }
+
+            condition_fields.push(ConditionField {
+                column_ident,
+                field_ident,
+                optional: is_option_type(&field.ty),
+            });
+        }
 
         Ok(DeriveModelCondition {
-            column_idents,
+            crate_paths,
+            entity_path,
+            fields: condition_fields,
+            generics,
             ident,
+            unique_keys,
         })
     }
 
     fn expand(&self) -> TokenStream {
         let Self {
-            column_idents,
+            crate_paths:
+                CratePaths {
+                    sea_orm,
+                    sea_skipper,
+                },
+            entity_path,
+            generics,
             ident,
+            ..
         } = self;
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let all_condition = self.fields_condition(quote!(#sea_orm::Condition::all()), true);
+        let any_condition = self.fields_condition(quote!(#sea_orm::Condition::any()), false);
+        let partial_condition = self.fields_condition(quote!(#sea_orm::Condition::all()), false);
+        let unique_conditions = self.unique_conditions();
 
         quote!(
             #[automatically_derived]
-            impl sea_skipper::ModelCondition for #ident {
-                fn to_all_condition(self) -> sea_orm::Condition {
-                    sea_orm::Condition::all()
-                    #(.add(<Self::Entity as EntityTrait>::Column::#column_idents.eq(self.get(<Self::Entity as EntityTrait>::Column::#column_idents))))*
+            impl #impl_generics #sea_skipper::ModelCondition for #ident #ty_generics #where_clause {
+                type Entity = #entity_path;
+
+                fn all_condition(&self) -> #sea_orm::Condition {
+                    #all_condition
+                }
+
+                fn any_condition(&self) -> #sea_orm::Condition {
+                    #any_condition
+                }
+
+                fn partial_condition(&self) -> #sea_orm::Condition {
+                    #partial_condition
+                }
+
+                fn unique_conditions(&self) -> ::std::vec::Vec<#sea_orm::Condition> {
+                    #unique_conditions
                 }
             }
         )
     }
-}
 
-pub fn expand_derive_model_condition(input: syn::DeriveInput) -> syn::Result<TokenStream> {
-    let ident_span = input.ident.span();
+    /// Expands to a condition (`base`) comparing each field with its column.
+    ///
+    /// `Option` fields whose value is `None` are compared with `IS NULL` when `none_is_null`,
+    /// otherwise they are skipped (`= NULL` never matches).
+    fn fields_condition(&self, base: TokenStream, none_is_null: bool) -> TokenStream {
+        let sea_orm = &self.crate_paths.sea_orm;
+        let adds = self.fields.iter().map(
+            |ConditionField {
+                 column_ident,
+                 field_ident,
+                 optional,
+             }| {
+                let column = self.column_path(column_ident);
+                let add = quote!(
+                    condition.add(#sea_orm::ColumnTrait::eq(&#column, self.#field_ident.clone()))
+                );
+                let none = if none_is_null {
+                    quote!(condition.add(#sea_orm::ColumnTrait::is_null(&#column)))
+                } else {
+                    quote!(condition)
+                };
+                if *optional {
+                    quote!(
+                        let condition = if self.#field_ident.is_some() { #add } else { #none };
+                    )
+                } else {
+                    quote!(
+                        let condition = #add;
+                    )
+                }
+            },
+        );
+
+        quote!(
+            let condition = #base;
+            #(#adds)*
+            condition
+        )
+    }
+
+    /// Returns the ident of the field of column `column_ident`.
+    fn field_ident(&self, column_ident: &Ident) -> &Ident {
+        // Unique key columns are always condition fields (`condition = false` is rejected on them).
+        &self
+            .fields
+            .iter()
+            .find(|field| &field.column_ident == column_ident)
+            .expect("unique key column is a condition field")
+            .field_ident
+    }
+
+    /// Expands to a `Vec` with an `ALL` condition for each unique key.
+    fn unique_conditions(&self) -> TokenStream {
+        let sea_orm = &self.crate_paths.sea_orm;
+        let conditions = self.unique_keys.iter().map(|column_idents| {
+            let adds = column_idents.iter().map(|column_ident| {
+                let field_ident = self.field_ident(column_ident);
+                let column = self.column_path(column_ident);
+                quote!(.add(#sea_orm::ColumnTrait::eq(&#column, self.#field_ident.clone())))
+            });
+            quote!(
+                #sea_orm::Condition::all()
+                #(#adds)*
+            )
+        });
 
-    match DeriveModelCondition::new(input) {
-        Ok(new_model) => Ok(new_model.expand()),
-        Err(Error::InputNotStruct) => Ok(quote_spanned! {
-            ident_span => compile_error!("you can only derive DeriveModelCondition on structs");
-        }),
+        quote!(::std::vec![#(#conditions),*])
     }
+
+    /// Expands to the path of the entity's `Column` variant `column_ident`.
+    fn column_path(&self, column_ident: &Ident) -> TokenStream {
+        let CratePaths {
+            sea_orm,
+            sea_skipper,
+        } = &self.crate_paths;
+        quote!(
+            <<Self as #sea_skipper::ModelCondition>::Entity as #sea_orm::EntityTrait>::Column::#column_ident
+        )
+    }
+}
+
+pub fn expand_derive_model_condition(input: syn::DeriveInput) -> syn::Result<TokenStream> {
+    Ok(DeriveModelCondition::new(input)?.expand())
 }
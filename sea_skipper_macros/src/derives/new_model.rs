@@ -1,158 +1,288 @@
 use heck::ToUpperCamelCase;
-use proc_macro2::{Span, TokenStream};
-use quote::{format_ident, quote, quote_spanned};
-use syn::{punctuated::Punctuated, token::Comma, Ident, Lit, Meta, Type};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::{ext::IdentExt, Attribute, Expr, Generics, Ident, Path, Type, Visibility};
 
-use crate::attributes::derive_attr;
+use crate::attributes::{derive_attr, field_attr};
 
-use super::util::{escape_rust_keyword, not_sea_orm_attr, trim_starting_raw_identifier};
-
-enum Error {
-    InputNotStruct,
-    Syn(syn::Error),
-}
+use super::util::{
+    column_ident, derives_serde, generic_params, is_option_type, named_fields, not_sea_orm_attr,
+    not_skipper_attr, serde_field_name, serde_rename_all, struct_derives, CratePaths,
+};
+use super::validate::{impl_validate, ValidatedField};
 
 struct DeriveNewModel {
+    active_model_ident: Ident,
+    attrs: Vec<Attribute>,
     column_idents: Vec<Ident>,
-    entity_ident: Ident,
+    crate_paths: CratePaths,
+    derives: Vec<Path>,
+    entity_path: Path,
+    generics: Generics,
     ident: Ident,
+    vis: Visibility,
+    field_attrs: Vec<Vec<Attribute>>,
+    /// Default value expressions of fields that may be omitted from the builder (`Option` fields &
+    /// fields with `#[skipper(default)]`), `None` for required fields.
+    field_defaults: Vec<Option<Expr>>,
     field_idents: Vec<Ident>,
     field_types: Vec<Type>,
+    /// Ident of the `Model` deriving `DeriveNewModel`.
+    model_ident: Ident,
+    /// Primary key fields of the `Model` & their types.
+    primary_keys: Vec<(Ident, Type)>,
+    /// Fields excluded with `#[skipper(skip_new = "...")]` & their default value expressions.
+    skipped_defaults: Vec<(Ident, Expr)>,
+    /// Fields excluded with `#[skipper(skip_new)]` (without a default value expression).
+    skipped_idents: Vec<Ident>,
+    validated_fields: Vec<ValidatedField>,
 }
 
 impl DeriveNewModel {
-    fn new(input: syn::DeriveInput) -> Result<Self, Error> {
-        // Extract named fields from the struct.
-        let fields = match input.data {
-            syn::Data::Struct(syn::DataStruct {
-                fields: syn::Fields::Named(syn::FieldsNamed { named, .. }),
-                ..
-            }) => named,
-            _ => return Err(Error::InputNotStruct),
-        };
+    fn new(input: syn::DeriveInput) -> syn::Result<Self> {
+        let model_ident = input.ident;
+        let generics = input.generics;
+
+        let fields = named_fields(&model_ident, input.data, "DeriveNewModel")?;
 
         // Parse model attributes.
-        let sea_attr = derive_attr::SeaOrm::try_from_attributes(&input.attrs)
-            .map_err(Error::Syn)?
-            .unwrap_or_default();
+        let sea_attr = derive_attr::SeaOrm::from_attributes(&input.attrs)?;
+
+        let skipper_attr = derive_attr::Skipper::from_attributes(&input.attrs)?;
+
+        // `serde` attributes (if the `NewModel` derives `serde` traits) and
+        // `#[skipper(new_model_attr(...))]` are added to the `NewModel` struct.
+        let forward_serde = derives_serde(
+            &skipper_attr.new_model,
+            cfg!(feature = "derive-newmodel-with-serde"),
+        );
+        let is_forwarded_serde_attr =
+            |attr: &Attribute| forward_serde && attr.path.is_ident("serde");
+        let mut attrs: Vec<Attribute> = input
+            .attrs
+            .iter()
+            .filter(|attr| is_forwarded_serde_attr(attr))
+            .cloned()
+            .collect();
+        attrs.extend(
+            skipper_attr
+                .new_model_attrs
+                .iter()
+                .map(|meta| -> Attribute { syn::parse_quote!(#[#meta]) }),
+        );
+
+        let ident = skipper_attr
+            .new_model
+            .name
+            .clone()
+            .unwrap_or_else(|| format_ident!("NewModel"));
+        let vis = skipper_attr
+            .new_model
+            .vis
+            .clone()
+            .unwrap_or_else(|| syn::parse_quote!(pub));
+        let active_model_ident = sea_attr
+            .active_model
+            .unwrap_or_else(|| format_ident!("ActiveModel"));
+        let entity_path = sea_attr
+            .entity
+            .clone()
+            .unwrap_or_else(|| syn::parse_quote!(Entity));
+        let crate_paths = CratePaths::new(&skipper_attr);
 
-        let ident = format_ident!("NewModel");
-        let entity_ident = sea_attr.entity.unwrap_or_else(|| format_ident!("Entity"));
+        let rename_all = serde_rename_all(&attrs);
 
         // Determined when iterating on fields & their attributes
         let mut column_idents: Vec<Ident> = Vec::new();
+        let mut field_attrs: Vec<Vec<Attribute>> = Vec::new();
+        let mut field_defaults: Vec<Option<Expr>> = Vec::new();
         let mut field_idents: Vec<Ident> = Vec::new();
         let mut field_types: Vec<Type> = Vec::new();
+        let mut primary_keys: Vec<(Ident, Type)> = Vec::new();
+        let mut skipped_defaults: Vec<(Ident, Expr)> = Vec::new();
+        let mut skipped_idents: Vec<Ident> = Vec::new();
+        let mut validated_fields: Vec<ValidatedField> = Vec::new();
 
         for field in fields {
             if let Some(ident) = &field.ident {
-                // Whether #[sea_orm(primary_key)] is on this field (if so, will be ignored)
-                let mut is_primary_key = false;
-                // #[sea_orm(enum_name = ...)] determines the Column variant ident (if present).
-                let mut enum_name: Option<Ident> = None;
-
-                for attr in field.attrs.iter() {
-                    if not_sea_orm_attr(attr) {
-                        continue;
-                    }
+                let sea_attr = field_attr::SeaOrm::from_field(&field)?;
+                let skipper_attr = field_attr::Skipper::from_field(&field)?;
 
-                    if let Ok(list) =
-                        attr.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)
-                    {
-                        for meta in list.iter() {
-                            match meta {
-                                Meta::NameValue(nv) => {
-                                    if let Some(name) = nv.path.get_ident() {
-                                        if name == "enum_name" {
-                                            if let Lit::Str(litstr) = &nv.lit {
-                                                enum_name =
-                                                    syn::parse_str(&litstr.value()).unwrap();
-                                            }
-                                        }
-                                    }
-                                }
-                                Meta::Path(p) => {
-                                    if let Some(name) = p.get_ident() {
-                                        if name == "primary_key" {
-                                            is_primary_key = true;
-                                        }
-                                    }
-                                }
-                                _ => {}
-                            }
-                        }
-                    }
+                // Primary keys are not part of the `NewModel` (they're given to `into_model()`).
+                if sea_attr.primary_key {
+                    primary_keys.push((ident.clone(), field.ty));
+                    continue;
                 }
 
-                // Skip storing info about this field if it's a primary key
-                if is_primary_key {
+                // Skip fields with `#[skipper(skip_new)]`, keeping their default value (if any).
+                // Read-only fields are never accepted, so they're skipped too.
+                if skipper_attr.skip_new || skipper_attr.read_only {
+                    match skipper_attr.skip_new_default {
+                        Some(default) => skipped_defaults.push((ident.clone(), default)),
+                        None => skipped_idents.push(ident.clone()),
+                    }
                     continue;
                 }
 
-                // Use enum_name if it was found in the attributes,
-                let column_ident = if let Some(enum_name) = enum_name {
-                    enum_name
-                // otherwise it's based on the field name.
-                } else {
-                    let field_name = field.ident.as_ref().unwrap().to_string();
-                    let field_name = trim_starting_raw_identifier(field_name).to_upper_camel_case();
-                    Ident::new(&escape_rust_keyword(field_name), Span::call_site())
-                };
+                // Use enum_name if it was found in the attributes, otherwise it's based on the field name.
+                let column_ident = sea_attr.enum_name.unwrap_or_else(|| column_ident(ident));
+
+                // `sea_orm` & `skipper` attributes are carried over so that `DeriveModelCondition`
+                // on the `NewModel` uses the same columns & unique keys as the `Model`. `serde`
+                // attributes are carried over so the `NewModel` (de)serializes like the `Model`.
+                let mut attrs: Vec<Attribute> = field
+                    .attrs
+                    .iter()
+                    .filter(|attr| {
+                        !not_sea_orm_attr(attr)
+                            || !not_skipper_attr(attr)
+                            || is_forwarded_serde_attr(attr)
+                    })
+                    .cloned()
+                    .collect();
+                attrs.extend(
+                    skipper_attr
+                        .new_model_attrs
+                        .iter()
+                        .map(|meta| -> Attribute { syn::parse_quote!(#[#meta]) }),
+                );
+
+                // `None` values of `Option` fields are valid.
+                if !skipper_attr.validators.is_empty() {
+                    validated_fields.push(ValidatedField {
+                        path: serde_field_name(ident, &attrs, rename_all.as_deref()),
+                        value: if is_option_type(&field.ty) {
+                            quote!(self.#ident.as_ref())
+                        } else {
+                            quote!(::std::option::Option::Some(&self.#ident))
+                        },
+                        validators: skipper_attr.validators,
+                    });
+                }
+
+                // `Option` fields default to `None` in the builder.
+                let default = skipper_attr.default.or_else(|| {
+                    is_option_type(&field.ty)
+                        .then(|| syn::parse_quote!(::std::option::Option::None))
+                });
 
                 column_idents.push(column_ident);
+                field_attrs.push(attrs);
+                field_defaults.push(default);
                 field_idents.push(ident.clone());
                 field_types.push(field.ty);
             }
         }
 
+        let sea_skipper = &crate_paths.sea_skipper;
+        let mut default_derives: Vec<Path> = vec![
+            syn::parse_quote!(Clone),
+            syn::parse_quote!(Debug),
+            syn::parse_quote!(PartialEq),
+            syn::parse_quote!(Eq),
+        ];
+        if cfg!(feature = "derive-newmodel-with-serde") {
+            default_derives.push(syn::parse_quote!(::serde::Deserialize));
+            default_derives.push(syn::parse_quote!(::serde::Serialize));
+        }
+        let derives = struct_derives(
+            &ident,
+            &skipper_attr.new_model,
+            // Conditions of the `NewModel` are used by e.g. `create_idempotent()`.
+            vec![syn::parse_quote!(#sea_skipper::DeriveModelCondition)],
+            default_derives,
+            &field_types.iter().collect::<Vec<_>>(),
+        )?;
+
+        // `DeriveModelCondition` on the `NewModel` needs the `Model`'s entity (if not `Entity`) &
+        // crate paths (if overridden).
+        let derives_model_condition = derives.iter().any(|path| {
+            path.segments
+                .last()
+                .is_some_and(|segment| segment.ident == "DeriveModelCondition")
+        });
+        if derives_model_condition {
+            if sea_attr.entity.is_some() {
+                let entity = entity_path.to_token_stream().to_string();
+                attrs.push(syn::parse_quote!(#[sea_orm(entity = #entity)]));
+            }
+            attrs.extend(CratePaths::overrides(&skipper_attr));
+        }
+
         Ok(DeriveNewModel {
+            active_model_ident,
+            attrs,
             column_idents,
+            crate_paths,
+            derives,
+            entity_path,
+            generics,
             ident,
-            entity_ident,
+            vis,
+            field_attrs,
+            field_defaults,
             field_idents,
             field_types,
+            model_ident,
+            primary_keys,
+            skipped_defaults,
+            skipped_idents,
+            validated_fields,
         })
     }
 
     fn expand(&self) -> syn::Result<TokenStream> {
         let expanded_new_model_struct = self.new_model_struct();
-        let expanded_impl_model_trait = self.impl_model_trait();
+        let expanded_builder = self.builder();
+        let expanded_impl_into_active_model = self.impl_into_active_model();
+        let expanded_impl_new_model_trait = self.impl_new_model_trait();
+        let expanded_impl_validate = impl_validate(
+            &self.crate_paths.sea_skipper,
+            &self.ident,
+            &self.generics,
+            &self.validated_fields,
+        );
+        let expanded_model_conversions = self.model_conversions();
 
         Ok(TokenStream::from_iter([
             expanded_new_model_struct,
-            expanded_impl_model_trait,
+            expanded_builder,
+            expanded_impl_into_active_model,
+            expanded_model_conversions,
+            expanded_impl_new_model_trait,
+            expanded_impl_validate,
         ]))
     }
 
     fn new_model_struct(&self) -> TokenStream {
         let Self {
+            attrs,
+            derives,
+            generics,
             ident,
+            vis,
+            field_attrs,
             field_idents,
             field_types,
             ..
         } = self;
-
-        let serde_derives = if cfg!(feature = "derive-newmodel-with-serde") {
-            quote! {Deserialize, Serialize }
-        } else {
-            quote! {}
-        };
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
         quote!(
             #[automatically_derived]
-            #[derive(
-                Clone, Debug, PartialEq, Eq, DeriveIntoActiveModel, sea_skipper::DeriveModelCondition, #serde_derives)
-            ]
+            #[derive(#(#derives),*)]
             #[doc = " Generated by [`sea_skipper_macros::DeriveNewModel`]"]
-            pub struct #ident {
+            #(#attrs)*
+            #vis struct #ident #generics #where_clause {
                 #(
-                    pub #field_idents: #field_types,
+                    #(#field_attrs)*
+                    #vis #field_idents: #field_types,
                 )*
             }
 
             #[automatically_derived]
-            impl #ident {
-                pub fn new(#(#field_idents: #field_types,)*) -> Self {
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #vis fn new(#(#field_idents: #field_types,)*) -> Self {
                     Self {
                         #(#field_idents),*
                     }
@@ -161,35 +291,346 @@ impl DeriveNewModel {
         )
     }
 
-    fn impl_model_trait(&self) -> TokenStream {
+    /// Expands to a `NewModel::builder()` method & the builder struct it returns.
+    ///
+    /// The builder has a type parameter for each field's state (`()` when it's not set & `(T,)`
+    /// when it is set), so `build()` only exists once all required fields are set & each setter can
+    /// only be called once. The builder also carries the generics of the `NewModel`.
+    fn builder(&self) -> TokenStream {
         let Self {
-            column_idents,
+            crate_paths: CratePaths { sea_skipper, .. },
+            generics,
+            ident,
+            vis,
+            field_defaults,
+            field_idents,
+            field_types,
+            ..
+        } = self;
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let (model_params, model_args) = generic_params(generics);
+        let where_predicates = where_clause.map(|where_clause| &where_clause.predicates);
+
+        let builder_ident = format_ident!("{ident}Builder");
+        let params: Vec<Ident> = field_idents
+            .iter()
+            .map(|field_ident| {
+                let name = field_ident.unraw().to_string().to_upper_camel_case();
+                format_ident!("__{name}")
+            })
+            .collect();
+        let builder_doc = format!(" Builder of [`{ident}`] returned by [`{ident}::builder()`].");
+        let build_doc = format!(" Builds the [`{ident}`].");
+
+        let setters = field_idents.iter().enumerate().map(|(i, field_ident)| {
+            let ty = &field_types[i];
+            let other_params = params
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, param)| param);
+            let unset_params =
+                params.iter().enumerate().map(
+                    |(j, param)| {
+                        if j == i {
+                            quote!(())
+                        } else {
+                            quote!(#param)
+                        }
+                    },
+                );
+            let set_params = params.iter().enumerate().map(|(j, param)| {
+                if j == i {
+                    quote!((#ty,))
+                } else {
+                    quote!(#param)
+                }
+            });
+            let other_idents = field_idents
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, other)| other);
+            let doc = format!(" Sets `{}`.", field_ident.unraw());
+
+            quote!(
+                #[automatically_derived]
+                impl<#(#model_params,)* #(#other_params),*>
+                    #builder_ident<#(#model_args,)* #(#unset_params),*>
+                #where_clause
+                {
+                    #[doc = #doc]
+                    #vis fn #field_ident(
+                        self,
+                        value: impl ::std::convert::Into<#ty>,
+                    ) -> #builder_ident<#(#model_args,)* #(#set_params),*> {
+                        #builder_ident {
+                            #field_ident: (value.into(),),
+                            #(#other_idents: self.#other_idents,)*
+                            __model: ::std::marker::PhantomData,
+                        }
+                    }
+                }
+            )
+        });
+
+        // Required fields must be set to `build()`, while optional fields may be in either state.
+        let mut build_params: Vec<TokenStream> = Vec::new();
+        let mut build_bounds: Vec<TokenStream> = Vec::new();
+        let mut build_values: Vec<TokenStream> = Vec::new();
+        for (((field_ident, ty), default), param) in field_idents
+            .iter()
+            .zip(field_types)
+            .zip(field_defaults)
+            .zip(&params)
+        {
+            match default {
+                Some(default) => {
+                    build_params.push(quote!(#param));
+                    build_bounds.push(quote!(#param: #sea_skipper::BuilderField<#ty>));
+                    build_values.push(quote!(
+                        #sea_skipper::BuilderField::value_or_else(self.#field_ident, || #default)
+                    ));
+                }
+                None => {
+                    build_params.push(quote!((#ty,)));
+                    build_values.push(quote!(self.#field_ident.0));
+                }
+            }
+        }
+        let optional_params = field_defaults
+            .iter()
+            .zip(&params)
+            .filter(|(default, _)| default.is_some())
+            .map(|(_, param)| param);
+
+        quote!(
+            #[automatically_derived]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// Returns a builder setting the fields of a new instance by name.
+                ///
+                /// `build()` can be called once all fields are set, except `Option` fields and
+                /// fields with `#[skipper(default)]` (which may be omitted).
+                #vis fn builder() -> #builder_ident<#(#model_args),*> {
+                    #builder_ident {
+                        #(#field_idents: (),)*
+                        __model: ::std::marker::PhantomData,
+                    }
+                }
+            }
+
+            #[automatically_derived]
+            #[doc = #builder_doc]
+            #[must_use]
+            #vis struct #builder_ident<#(#model_params,)* #(#params = ()),*> #where_clause {
+                #(#field_idents: #params,)*
+                __model: ::std::marker::PhantomData<fn() -> #ident #ty_generics>,
+            }
+
+            #(#setters)*
+
+            #[automatically_derived]
+            impl<#(#model_params,)* #(#optional_params),*>
+                #builder_ident<#(#model_args,)* #(#build_params),*>
+            where
+                #(#build_bounds,)*
+                #where_predicates
+            {
+                #[doc = #build_doc]
+                #vis fn build(self) -> #ident #ty_generics {
+                    #ident {
+                        #(#field_idents: #build_values,)*
+                    }
+                }
+            }
+        )
+    }
+
+    /// Implements `IntoActiveModel` on the `NewModel`.
+    ///
+    /// Fields of the `NewModel` are `Set` and fields with `#[skipper(skip_new = "...")]` are `Set` to
+    /// their default value. Other fields (e.g. primary keys) are `NotSet`.
+    fn impl_into_active_model(&self) -> TokenStream {
+        let Self {
+            active_model_ident,
+            crate_paths: CratePaths { sea_orm, .. },
+            generics,
             ident,
-            entity_ident,
             field_idents,
+            skipped_defaults,
             ..
         } = self;
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-        let missing_field_msg = format!("field does not exist on {ident}");
+        let (skipped_idents, skipped_defaults): (Vec<_>, Vec<_>) =
+            skipped_defaults.iter().cloned().unzip();
 
         quote!(
             #[automatically_derived]
-            impl sea_orm::ModelTrait for #ident {
-                type Entity = #entity_ident;
+            impl #impl_generics #sea_orm::IntoActiveModel<#active_model_ident> for #ident #ty_generics #where_clause {
+                fn into_active_model(self) -> #active_model_ident {
+                    #active_model_ident {
+                        #(#field_idents: #sea_orm::IntoActiveValue::<_>::into_active_value(self.#field_idents).into(),)*
+                        #(#skipped_idents: #sea_orm::ActiveValue::Set(#skipped_defaults),)*
+                        ..::std::default::Default::default()
+                    }
+                }
+            }
+        )
+    }
+
+    /// Implements `From<Model>` on the `NewModel`, and `into_model()` &
+    /// `into_active_model_with_id()` methods that add the primary key to a `NewModel`.
+    ///
+    /// `into_model()` is only implemented if every skipped field has a `skip_new = "..."` default
+    /// (there's no value for the other skipped fields).
+    ///
+    /// The `id` of a composite primary key is a tuple of the key's fields (in declaration order).
+    fn model_conversions(&self) -> TokenStream {
+        let Self {
+            active_model_ident,
+            crate_paths: CratePaths { sea_orm, .. },
+            generics,
+            ident,
+            vis,
+            field_idents,
+            model_ident,
+            primary_keys,
+            skipped_defaults,
+            skipped_idents,
+            ..
+        } = self;
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-                fn get(&self, c: <Self::Entity as sea_orm::entity::EntityTrait>::Column) -> sea_orm::Value {
-                    match c {
-                        #(<Self::Entity as sea_orm::entity::EntityTrait>::Column::#column_idents => self.#field_idents.clone().into(),)*
-                        _ => panic!(#missing_field_msg),
+        let expanded_impl_from_model = quote!(
+            #[automatically_derived]
+            impl #impl_generics ::std::convert::From<#model_ident #ty_generics> for #ident #ty_generics #where_clause {
+                fn from(model: #model_ident #ty_generics) -> Self {
+                    Self {
+                        #(#field_idents: model.#field_idents,)*
                     }
                 }
+            }
+        );
+
+        if primary_keys.is_empty() {
+            return expanded_impl_from_model;
+        }
 
-                fn set(&mut self, c: <Self::Entity as sea_orm::entity::EntityTrait>::Column, v: sea_orm::Value) {
-                    match c {
-                        #(<Self::Entity as sea_orm::entity::EntityTrait>::Column::#column_idents => self.#field_idents = v.unwrap(),)*
-                        _ => panic!(#missing_field_msg),
+        let (key_idents, key_types): (Vec<_>, Vec<_>) = primary_keys.iter().cloned().unzip();
+        let (id_pattern, id_type) = match (key_idents.as_slice(), key_types.as_slice()) {
+            ([key_ident], [key_type]) => (quote!(#key_ident), quote!(#key_type)),
+            _ => (quote!((#(#key_idents),*)), quote!((#(#key_types),*))),
+        };
+        let (skipped_default_idents, skipped_defaults): (Vec<_>, Vec<_>) =
+            skipped_defaults.iter().cloned().unzip();
+        let into_model = skipped_idents.is_empty().then(|| {
+            quote!(
+                /// Converts `self` into a `Model` with the primary key `id`.
+                ///
+                /// Fields excluded with `#[skipper(skip_new = "...")]` are set to their default
+                /// value.
+                #vis fn into_model(self, id: #id_type) -> #model_ident #ty_generics {
+                    let #id_pattern = id;
+                    #model_ident {
+                        #(#key_idents,)*
+                        #(#field_idents: self.#field_idents,)*
+                        #(#skipped_default_idents: #skipped_defaults,)*
                     }
                 }
+            )
+        });
+
+        quote!(
+            #expanded_impl_from_model
+
+            #[automatically_derived]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #into_model
+
+                /// Converts `self` into an `ActiveModel` with the primary key `id` `Set` (e.g. to
+                /// insert a record with a client-chosen ID).
+                #vis fn into_active_model_with_id(self, id: #id_type) -> #active_model_ident {
+                    let #id_pattern = id;
+                    let mut active_model = #sea_orm::IntoActiveModel::into_active_model(self);
+                    #(active_model.#key_idents = #sea_orm::ActiveValue::Set(#key_idents);)*
+                    active_model
+                }
+            }
+        )
+    }
+
+    /// Implements `NewModelTrait` on the `NewModel`.
+    ///
+    /// Columns that are not carried by the `NewModel` (e.g. primary keys) & values that don't
+    /// convert to a field's type are errors.
+    fn impl_new_model_trait(&self) -> TokenStream {
+        let Self {
+            column_idents,
+            crate_paths:
+                CratePaths {
+                    sea_orm,
+                    sea_skipper,
+                },
+            entity_path,
+            generics,
+            ident,
+            field_idents,
+            field_types,
+            ..
+        } = self;
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        quote!(
+            #[automatically_derived]
+            impl #impl_generics #sea_skipper::NewModelTrait for #ident #ty_generics #where_clause {
+                type Entity = #entity_path;
+
+                fn columns() -> &'static [<Self::Entity as #sea_orm::EntityTrait>::Column] {
+                    &[#(<Self::Entity as #sea_orm::EntityTrait>::Column::#column_idents),*]
+                }
+
+                fn try_get(
+                    &self,
+                    column: <Self::Entity as #sea_orm::EntityTrait>::Column,
+                ) -> ::std::result::Result<#sea_orm::Value, #sea_skipper::NewModelError> {
+                    #[allow(unreachable_patterns)]
+                    match column {
+                        #(<Self::Entity as #sea_orm::EntityTrait>::Column::#column_idents => {
+                            ::std::result::Result::Ok(self.#field_idents.clone().into())
+                        })*
+                        _ => ::std::result::Result::Err(#sea_skipper::NewModelError::MissingColumn(
+                            #sea_orm::IdenStatic::as_str(&column).to_string(),
+                        )),
+                    }
+                }
+
+                fn try_set(
+                    &mut self,
+                    column: <Self::Entity as #sea_orm::EntityTrait>::Column,
+                    value: #sea_orm::Value,
+                ) -> ::std::result::Result<(), #sea_skipper::NewModelError> {
+                    #[allow(unreachable_patterns)]
+                    match column {
+                        #(<Self::Entity as #sea_orm::EntityTrait>::Column::#column_idents => {
+                            self.#field_idents =
+                                <#field_types as #sea_orm::sea_query::ValueType>::try_from(value)
+                                    .map_err(|_| {
+                                        #sea_skipper::NewModelError::TypeMismatch(
+                                            #sea_orm::IdenStatic::as_str(&column).to_string(),
+                                        )
+                                    })?;
+                        })*
+                        _ => {
+                            return ::std::result::Result::Err(
+                                #sea_skipper::NewModelError::MissingColumn(
+                                    #sea_orm::IdenStatic::as_str(&column).to_string(),
+                                ),
+                            )
+                        }
+                    }
+                    ::std::result::Result::Ok(())
+                }
             }
         )
     }
@@ -197,13 +638,5 @@ impl DeriveNewModel {
 
 /// Method to derive a `NewModel` from a `Model` definition.
 pub fn expand_derive_new_model(input: syn::DeriveInput) -> syn::Result<TokenStream> {
-    let ident_span = input.ident.span();
-
-    match DeriveNewModel::new(input) {
-        Ok(new_model) => new_model.expand(),
-        Err(Error::InputNotStruct) => Ok(quote_spanned! {
-            ident_span => compile_error!("you can only derive DeriveNewModel on structs");
-        }),
-        Err(Error::Syn(err)) => Err(err),
-    }
+    DeriveNewModel::new(input)?.expand()
 }
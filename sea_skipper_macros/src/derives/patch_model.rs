@@ -0,0 +1,446 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    punctuated::Punctuated, token::Comma, Attribute, Expr, Generics, Ident, Meta, NestedMeta, Path,
+    Type, Visibility,
+};
+
+use crate::attributes::{derive_attr, field_attr};
+
+use super::util::{
+    column_ident, derives_serde, named_fields, option_inner_type, serde_field_name,
+    serde_rename_all, struct_derives, CratePaths,
+};
+use super::validate::{impl_validate, ValidatedField};
+
+/// `serde` struct attributes carried over to the `PatchModel`.
+const SERDE_STRUCT_ATTRS: &[&str] = &["deny_unknown_fields", "rename", "rename_all"];
+
+/// `serde` field attributes carried over to the `PatchModel`.
+///
+/// Other attributes (e.g. `default` or `with`) may not apply to the `Option` & `PatchValue` types
+/// of `PatchModel` fields.
+const SERDE_FIELD_ATTRS: &[&str] = &["alias", "rename"];
+
+struct PatchField {
+    attrs: Vec<Attribute>,
+    /// Default value a write-only field is reset to when it's patched to `null` (if any).
+    default: Option<Expr>,
+    /// Name of the field in patch documents (its serialized name in the `ViewModel`).
+    document_name: String,
+    ident: Ident,
+    /// Serialized name of the field.
+    name: String,
+    /// `T` of a field that is a `PatchValue<T>` (i.e. a `Model` field with type `Option<T>`, or a
+    /// write-only field of type `T` with a default value), `None` for other fields.
+    nullable_type: Option<Type>,
+    ty: Type,
+}
+
+struct DerivePatchModel {
+    active_model_ident: Ident,
+    attrs: Vec<Attribute>,
+    crate_paths: CratePaths,
+    derives: Vec<Path>,
+    entity_path: Path,
+    generics: Generics,
+    ident: Ident,
+    vis: Visibility,
+    fields: Vec<PatchField>,
+    /// Columns of `#[skipper(read_only)]` fields.
+    read_only_column_idents: Vec<Ident>,
+    validated_fields: Vec<ValidatedField>,
+}
+
+impl DerivePatchModel {
+    fn new(input: syn::DeriveInput) -> syn::Result<Self> {
+        let fields = named_fields(&input.ident, input.data, "DerivePatchModel")?;
+
+        // Parse model attributes.
+        let sea_attr = derive_attr::SeaOrm::from_attributes(&input.attrs)?;
+
+        let skipper_attr = derive_attr::Skipper::from_attributes(&input.attrs)?;
+
+        // Some `serde` attributes (if the `PatchModel` derives `serde` traits) and
+        // `#[skipper(patch_model_attr(...))]` are added to the `PatchModel` struct.
+        let forward_serde = derives_serde(
+            &skipper_attr.patch_model,
+            cfg!(feature = "derive-patchmodel-with-serde"),
+        );
+        let mut attrs: Vec<Attribute> = input
+            .attrs
+            .iter()
+            .filter(|_| forward_serde)
+            .filter_map(|attr| forwarded_serde_attr(attr, SERDE_STRUCT_ATTRS))
+            .collect();
+        attrs.extend(
+            skipper_attr
+                .patch_model_attrs
+                .iter()
+                .map(|meta| -> Attribute { syn::parse_quote!(#[#meta]) }),
+        );
+
+        let ident = skipper_attr
+            .patch_model
+            .name
+            .clone()
+            .unwrap_or_else(|| format_ident!("PatchModel"));
+        let vis = skipper_attr
+            .patch_model
+            .vis
+            .clone()
+            .unwrap_or_else(|| syn::parse_quote!(pub));
+        let active_model_ident = sea_attr
+            .active_model
+            .unwrap_or_else(|| format_ident!("ActiveModel"));
+        let entity_path = sea_attr.entity.unwrap_or_else(|| syn::parse_quote!(Entity));
+        let crate_paths = CratePaths::new(&skipper_attr);
+        let generics = input.generics;
+        let is_absent_path = format!(
+            "{}::PatchValue::is_absent",
+            crate_paths.sea_skipper.to_token_stream()
+        );
+
+        let rename_all = serde_rename_all(&attrs);
+        let view_rename_all = serde_rename_all(&input.attrs);
+
+        let mut patch_fields: Vec<PatchField> = Vec::new();
+        let mut read_only_column_idents: Vec<Ident> = Vec::new();
+        let mut validated_fields: Vec<ValidatedField> = Vec::new();
+
+        for field in fields {
+            if let Some(ident) = &field.ident {
+                let sea_attr = field_attr::SeaOrm::from_field(&field)?;
+                let skipper_attr = field_attr::Skipper::from_field(&field)?;
+
+                // Primary keys identify the record to update, so they can't be patched.
+                if sea_attr.primary_key {
+                    continue;
+                }
+
+                // Read-only fields are never accepted.
+                if skipper_attr.read_only {
+                    read_only_column_idents
+                        .push(sea_attr.enum_name.unwrap_or_else(|| column_ident(ident)));
+                    continue;
+                }
+
+                let nullable_type = option_inner_type(&field.ty).cloned();
+
+                // Clients never see the value of a write-only field, so patching it to `null` must
+                // clear it (to `NULL` or its default value).
+                let default = if skipper_attr.write_only && nullable_type.is_none() {
+                    match skipper_attr.default {
+                        Some(default) => Some(default),
+                        None => {
+                            return Err(syn::Error::new_spanned(
+                                ident,
+                                "a `write_only` field must be an `Option` or have a \
+                                 `#[skipper(default)]` to be patched",
+                            ))
+                        }
+                    }
+                } else {
+                    None
+                };
+                // Fields that can be patched to `null` are `PatchValue`s.
+                let patch_value_type = nullable_type
+                    .clone()
+                    .or_else(|| default.is_some().then(|| field.ty.clone()));
+
+                // Absent fields are left out when serializing the `PatchModel` & `PatchValue`
+                // fields default to `PatchValue::Absent` when deserializing.
+                let mut attrs: Vec<Attribute> = Vec::new();
+                if forward_serde {
+                    attrs.push(if patch_value_type.is_some() {
+                        syn::parse_quote!(#[serde(default, skip_serializing_if = #is_absent_path)])
+                    } else {
+                        syn::parse_quote!(#[serde(default, skip_serializing_if = "::std::option::Option::is_none")])
+                    });
+                }
+                attrs.extend(
+                    field
+                        .attrs
+                        .iter()
+                        .filter(|_| forward_serde)
+                        .filter_map(|attr| forwarded_serde_attr(attr, SERDE_FIELD_ATTRS)),
+                );
+                attrs.extend(
+                    skipper_attr
+                        .patch_model_attrs
+                        .iter()
+                        .map(|meta| -> Attribute { syn::parse_quote!(#[#meta]) }),
+                );
+
+                let name = serde_field_name(ident, &attrs, rename_all.as_deref());
+                // Patch documents are applied to the `ViewModel`, which has neither hidden nor
+                // write-only fields (so they keep their `PatchModel` name).
+                let document_name = if skipper_attr.hidden || skipper_attr.write_only {
+                    name.clone()
+                } else {
+                    let view_attrs: Vec<Attribute> = field
+                        .attrs
+                        .iter()
+                        .filter(|attr| attr.path.is_ident("serde"))
+                        .cloned()
+                        .chain(
+                            skipper_attr
+                                .view_model_attrs
+                                .iter()
+                                .map(|meta| -> Attribute { syn::parse_quote!(#[#meta]) }),
+                        )
+                        .collect();
+                    serde_field_name(
+                        skipper_attr.view_rename.as_ref().unwrap_or(ident),
+                        &view_attrs,
+                        view_rename_all.as_deref(),
+                    )
+                };
+
+                // Absent fields & `null` values of nullable fields are valid.
+                if !skipper_attr.validators.is_empty() {
+                    validated_fields.push(ValidatedField {
+                        path: name.clone(),
+                        value: if patch_value_type.is_some() {
+                            quote!(self.#ident.as_ref().into_option().flatten())
+                        } else {
+                            quote!(self.#ident.as_ref())
+                        },
+                        validators: skipper_attr.validators,
+                    });
+                }
+
+                patch_fields.push(PatchField {
+                    attrs,
+                    default,
+                    document_name,
+                    ident: ident.clone(),
+                    name,
+                    nullable_type: patch_value_type,
+                    ty: field.ty,
+                });
+            }
+        }
+
+        let mut default_derives: Vec<Path> = vec![
+            syn::parse_quote!(Clone),
+            syn::parse_quote!(Debug),
+            syn::parse_quote!(Default),
+            syn::parse_quote!(PartialEq),
+            syn::parse_quote!(Eq),
+        ];
+        if cfg!(feature = "derive-patchmodel-with-serde") {
+            default_derives.push(syn::parse_quote!(::serde::Deserialize));
+            default_derives.push(syn::parse_quote!(::serde::Serialize));
+        }
+        let field_types: Vec<&Type> = patch_fields.iter().map(|field| &field.ty).collect();
+        let derives = struct_derives(
+            &ident,
+            &skipper_attr.patch_model,
+            Vec::new(),
+            default_derives,
+            &field_types,
+        )?;
+
+        Ok(DerivePatchModel {
+            active_model_ident,
+            attrs,
+            crate_paths,
+            derives,
+            entity_path,
+            generics,
+            ident,
+            vis,
+            fields: patch_fields,
+            read_only_column_idents,
+            validated_fields,
+        })
+    }
+
+    fn expand(&self) -> syn::Result<TokenStream> {
+        let expanded_patch_model_struct = self.patch_model_struct();
+        let expanded_impl_into_active_model = self.impl_into_active_model();
+        let expanded_impl_patch_model_trait = self.impl_patch_model_trait();
+        let expanded_impl_validate = impl_validate(
+            &self.crate_paths.sea_skipper,
+            &self.ident,
+            &self.generics,
+            &self.validated_fields,
+        );
+
+        Ok(TokenStream::from_iter([
+            expanded_patch_model_struct,
+            expanded_impl_into_active_model,
+            expanded_impl_patch_model_trait,
+            expanded_impl_validate,
+        ]))
+    }
+
+    fn patch_model_struct(&self) -> TokenStream {
+        let Self {
+            attrs,
+            crate_paths: CratePaths { sea_skipper, .. },
+            derives,
+            generics,
+            ident,
+            vis,
+            fields,
+            ..
+        } = self;
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let field_attrs = fields.iter().map(|field| &field.attrs);
+        let field_idents: Vec<&Ident> = fields.iter().map(|field| &field.ident).collect();
+        let field_types = fields.iter().map(|field| match &field.nullable_type {
+            Some(inner) => quote!(#sea_skipper::PatchValue<#inner>),
+            None => {
+                let ty = &field.ty;
+                quote!(::std::option::Option<#ty>)
+            }
+        });
+        let is_absent = fields.iter().map(|field| {
+            let ident = &field.ident;
+            match &field.nullable_type {
+                Some(_) => quote!(self.#ident.is_absent()),
+                None => quote!(self.#ident.is_none()),
+            }
+        });
+
+        quote!(
+            #[automatically_derived]
+            #[derive(#(#derives),*)]
+            #[doc = " Generated by [`sea_skipper_macros::DerivePatchModel`]"]
+            #(#attrs)*
+            #vis struct #ident #generics #where_clause {
+                #(
+                    #(#field_attrs)*
+                    #vis #field_idents: #field_types,
+                )*
+            }
+
+            #[automatically_derived]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// Returns true if no field is provided (i.e. the patch does not change anything).
+                #vis fn is_empty(&self) -> bool {
+                    true #(&& #is_absent)*
+                }
+            }
+        )
+    }
+
+    /// Implements `PatchModelTrait` on the `PatchModel`.
+    fn impl_patch_model_trait(&self) -> TokenStream {
+        let Self {
+            crate_paths:
+                CratePaths {
+                    sea_orm,
+                    sea_skipper,
+                },
+            entity_path,
+            fields,
+            generics,
+            ident,
+            read_only_column_idents,
+            ..
+        } = self;
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let document_names = fields.iter().map(|field| &field.document_name);
+        let names = fields.iter().map(|field| &field.name);
+
+        quote!(
+            #[automatically_derived]
+            impl #impl_generics #sea_skipper::PatchModelTrait for #ident #ty_generics #where_clause {
+                type Entity = #entity_path;
+
+                fn read_only_columns() -> &'static [<Self::Entity as #sea_orm::EntityTrait>::Column] {
+                    &[#(<Self::Entity as #sea_orm::EntityTrait>::Column::#read_only_column_idents),*]
+                }
+
+                fn document_fields() -> &'static [(&'static str, &'static str)] {
+                    &[#((#document_names, #names)),*]
+                }
+            }
+        )
+    }
+
+    /// Implements `IntoActiveModel` on the `PatchModel`.
+    ///
+    /// Provided fields are `Set` (nullable fields patched to `null` are `Set(None)`). Absent fields
+    /// and fields that are not part of the `PatchModel` (e.g. primary keys) are `NotSet`.
+    fn impl_into_active_model(&self) -> TokenStream {
+        let Self {
+            active_model_ident,
+            crate_paths:
+                CratePaths {
+                    sea_orm,
+                    sea_skipper,
+                },
+            generics,
+            ident,
+            fields,
+            ..
+        } = self;
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let field_values = fields.iter().map(|field| {
+            let ident = &field.ident;
+            match (&field.nullable_type, &field.default) {
+                (Some(_), Some(default)) => quote!(
+                    #ident: match self.#ident {
+                        #sea_skipper::PatchValue::Absent => #sea_orm::ActiveValue::NotSet,
+                        #sea_skipper::PatchValue::Null => #sea_orm::ActiveValue::Set(#default),
+                        #sea_skipper::PatchValue::Value(value) => #sea_orm::ActiveValue::Set(value),
+                    }
+                ),
+                (Some(_), None) => quote!(
+                    #ident: #sea_orm::IntoActiveValue::<_>::into_active_value(self.#ident)
+                ),
+                (None, _) => quote!(
+                    #ident: self.#ident.map_or(#sea_orm::ActiveValue::NotSet, #sea_orm::ActiveValue::Set)
+                ),
+            }
+        });
+
+        quote!(
+            #[automatically_derived]
+            impl #impl_generics #sea_orm::IntoActiveModel<#active_model_ident> for #ident #ty_generics #where_clause {
+                fn into_active_model(self) -> #active_model_ident {
+                    #active_model_ident {
+                        #(#field_values,)*
+                        ..::std::default::Default::default()
+                    }
+                }
+            }
+        )
+    }
+}
+
+/// Returns a `#[serde(...)]` attribute with the items of `attr` named in `allowed` (if `attr` is
+/// a `serde` attribute with any of these items).
+fn forwarded_serde_attr(attr: &Attribute, allowed: &[&str]) -> Option<Attribute> {
+    if !attr.path.is_ident("serde") {
+        return None;
+    }
+    let Ok(Meta::List(list)) = attr.parse_meta() else {
+        return None;
+    };
+    let forwarded: Punctuated<NestedMeta, Comma> = list
+        .nested
+        .into_iter()
+        .filter(|nested| match nested {
+            NestedMeta::Meta(meta) => allowed.iter().any(|name| meta.path().is_ident(name)),
+            NestedMeta::Lit(_) => false,
+        })
+        .collect();
+    if forwarded.is_empty() {
+        None
+    } else {
+        Some(syn::parse_quote!(#[serde(#forwarded)]))
+    }
+}
+
+/// Method to derive a `PatchModel` from a `Model` definition.
+pub fn expand_derive_patch_model(input: syn::DeriveInput) -> syn::Result<TokenStream> {
+    DerivePatchModel::new(input)?.expand()
+}
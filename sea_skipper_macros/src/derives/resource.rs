@@ -0,0 +1,299 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ext::IdentExt, Data, DataStruct, Fields, FieldsNamed, Ident, LitStr, Path, Type};
+
+use crate::attributes::{derive_attr, field_attr};
+
+use super::util::{
+    column_ident, is_option_type, is_string_type, path_template, AlternateKey, CratePaths, Location,
+};
+
+struct DeriveResource {
+    active_model: Path,
+    /// `Column` variant of the `#[skipper(alternate_key)]` field (if any).
+    alternate_key: Option<Ident>,
+    crate_paths: CratePaths,
+    /// The `Model` of the resource (the input of a derive on a `Model`).
+    data: Path,
+    /// The `Entity` implementing `Resource` (the input of a derive on an `Entity`).
+    entity: Path,
+    /// Type of the primary key (a tuple for composite primary keys).
+    id: TokenStream,
+    /// `Location` of the `Model` from the `#[skipper(resource(path = "..."))]` route (if any).
+    location: Option<Location>,
+    view: Type,
+}
+
+impl DeriveResource {
+    fn new(input: syn::DeriveInput) -> syn::Result<Self> {
+        let sea_attr = derive_attr::SeaOrm::from_attributes(&input.attrs)?;
+        let skipper_attr = derive_attr::Skipper::from_attributes(&input.attrs)?;
+        let crate_paths = CratePaths::new(&skipper_attr);
+        let active_model: Path = match sea_attr.active_model {
+            Some(ident) => ident.into(),
+            None => syn::parse_quote!(ActiveModel),
+        };
+
+        let ident = input.ident;
+        let mut alternate_key = None;
+        let (entity, data, id, location) = match input.data {
+            // On a `Model`, the `Id` is the type of its primary key field(s).
+            Data::Struct(DataStruct {
+                fields: Fields::Named(FieldsNamed { named, .. }),
+                ..
+            }) => {
+                let mut primary_key_types: Vec<Type> = Vec::new();
+                let mut primary_key_idents: Vec<Ident> = Vec::new();
+                let mut field_idents: Vec<Ident> = Vec::new();
+                let mut location_alternate_key: Option<AlternateKey> = None;
+                for field in named {
+                    let field_ident = field.ident.clone().unwrap();
+                    let sea_attr = field_attr::SeaOrm::from_field(&field)?;
+                    if field_attr::Skipper::from_field(&field)?.alternate_key {
+                        let message = if !sea_attr.unique || sea_attr.primary_key {
+                            Some(
+                                "`#[skipper(alternate_key)]` must be on a `#[sea_orm(unique)]` \
+                                 field that is not part of the primary key",
+                            )
+                        } else if !is_string_type(&field.ty) {
+                            Some(
+                                "`#[skipper(alternate_key)]` must be on a `String` or \
+                                 `Option<String>` field (path segments are compared as strings)",
+                            )
+                        } else if alternate_key.is_some() {
+                            Some("only one field can be an `alternate_key`")
+                        } else {
+                            None
+                        };
+                        if let Some(message) = message {
+                            return Err(syn::Error::new_spanned(&field_ident, message));
+                        }
+                        alternate_key = Some(
+                            sea_attr
+                                .enum_name
+                                .unwrap_or_else(|| column_ident(&field_ident)),
+                        );
+                        location_alternate_key = Some(AlternateKey {
+                            field_ident: field_ident.clone(),
+                            optional: is_option_type(&field.ty),
+                        });
+                    }
+                    if sea_attr.primary_key {
+                        primary_key_types.push(field.ty);
+                        primary_key_idents.push(field_ident.clone());
+                    }
+                    field_idents.push(field_ident);
+                }
+                let id = match primary_key_types.as_slice() {
+                    [] => return Err(syn::Error::new_spanned(
+                        &ident,
+                        "`DeriveResource` needs a `#[sea_orm(primary_key)]` field for the `Id` \
+                             of the resource",
+                    )),
+                    [ty] => quote!(#ty),
+                    types => quote!((#(#types),*)),
+                };
+                let location = match &skipper_attr.resource.path {
+                    Some(template) => {
+                        let mut location = location(template, |name| {
+                            field_idents
+                                .iter()
+                                .find(|field_ident| field_ident.unraw() == name)
+                                .cloned()
+                                .ok_or_else(|| {
+                                    syn::Error::new_spanned(
+                                        template,
+                                        format!("unknown field `{name}` in the resource path"),
+                                    )
+                                })
+                        })?;
+                        // The alternate key is preferred over a single column primary key.
+                        if let ([primary_key], [primary_key_type]) =
+                            (primary_key_idents.as_slice(), primary_key_types.as_slice())
+                        {
+                            location.primary_key =
+                                Some((primary_key.clone(), primary_key_type.clone()));
+                            location.alternate_key = location_alternate_key;
+                        }
+                        Some(location)
+                    }
+                    None => None,
+                };
+                let entity = sea_attr.entity.unwrap_or_else(|| syn::parse_quote!(Entity));
+                (entity, ident.into(), id, location)
+            }
+            // On an `Entity`, the `Id` is the `ValueType` of its primary key.
+            Data::Struct(DataStruct {
+                fields: Fields::Unit,
+                ..
+            }) => {
+                let sea_orm = &crate_paths.sea_orm;
+                let id = quote!(
+                    <<Self as #sea_orm::EntityTrait>::PrimaryKey as #sea_orm::PrimaryKeyTrait>::ValueType
+                );
+                let location = match &skipper_attr.resource.path {
+                    Some(template) => Some(location(template, |name| {
+                        syn::parse_str::<Ident>(name)
+                            .or_else(|_| syn::parse_str::<Ident>(&format!("r#{name}")))
+                            .map_err(|_| {
+                                syn::Error::new_spanned(
+                                    template,
+                                    format!("invalid field name `{name}` in the resource path"),
+                                )
+                            })
+                    })?),
+                    None => None,
+                };
+                let data = sea_attr.model.unwrap_or_else(|| syn::parse_quote!(Model));
+                (ident.into(), data, id, location)
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &ident,
+                    "`DeriveResource` can only be derived on a `Model` (a struct with named \
+                     fields) or an `Entity` (a unit struct)",
+                ))
+            }
+        };
+
+        let view = skipper_attr.resource.view.unwrap_or_else(|| {
+            let data = &data;
+            syn::parse_quote!(#data)
+        });
+
+        Ok(DeriveResource {
+            active_model,
+            alternate_key,
+            crate_paths,
+            data,
+            entity,
+            id,
+            location,
+            view,
+        })
+    }
+
+    fn expand(&self) -> TokenStream {
+        let expanded_impl_resource = self.impl_resource();
+        let expanded_impl_resource_route = self.impl_resource_route();
+        let expanded_impl_location = self.impl_location();
+
+        TokenStream::from_iter([
+            expanded_impl_resource,
+            expanded_impl_resource_route,
+            expanded_impl_location,
+        ])
+    }
+
+    /// Implements `Resource` on the `Entity`.
+    fn impl_resource(&self) -> TokenStream {
+        let Self {
+            active_model,
+            alternate_key,
+            crate_paths:
+                CratePaths {
+                    sea_orm,
+                    sea_skipper,
+                },
+            data,
+            entity,
+            id,
+            view,
+            ..
+        } = self;
+
+        let alternate_key_fn = alternate_key.as_ref().map(|column| {
+            quote!(
+                fn alternate_key() -> ::std::option::Option<<Self as #sea_orm::EntityTrait>::Column> {
+                    ::std::option::Option::Some(<Self as #sea_orm::EntityTrait>::Column::#column)
+                }
+            )
+        });
+
+        quote!(
+            #[automatically_derived]
+            impl #sea_skipper::Resource for #entity {
+                type ActiveModel = #active_model;
+                type Data = #data;
+                type View = #view;
+                type Id = #id;
+
+                #alternate_key_fn
+            }
+        )
+    }
+
+    /// Implements `ResourceRoute` on the `Entity` with the `#[skipper(resource(path = "..."))]`
+    /// template.
+    fn impl_resource_route(&self) -> TokenStream {
+        let Self {
+            crate_paths: CratePaths { sea_skipper, .. },
+            entity,
+            location,
+            ..
+        } = self;
+
+        match location {
+            Some(Location { template, .. }) => quote!(
+                #[automatically_derived]
+                impl #sea_skipper::ResourceRoute for #entity {
+                    const ROUTE: #sea_skipper::RouteTemplate = #sea_skipper::RouteTemplate::new(#template);
+                }
+            ),
+            None => TokenStream::new(),
+        }
+    }
+
+    /// Implements `Location` on the `Model` with the `#[skipper(resource(path = "..."))]` template.
+    fn impl_location(&self) -> TokenStream {
+        let Self {
+            crate_paths: CratePaths { sea_skipper, .. },
+            data,
+            location,
+            ..
+        } = self;
+
+        match location {
+            Some(location) => location.expand(sea_skipper, data, &Default::default()),
+            None => TokenStream::new(),
+        }
+    }
+}
+
+/// Parses the path `template`, resolving the field of each placeholder with `field`.
+///
+/// Placeholders must be whole path segments since the template is also used for routes.
+fn location(
+    template: &LitStr,
+    mut field: impl FnMut(&str) -> syn::Result<Ident>,
+) -> syn::Result<Location> {
+    let names = path_template(template)?;
+    for segment in template.value().split('/') {
+        let unescaped = segment.replace("{{", "").replace("}}", "");
+        let is_placeholder = unescaped.starts_with('{') && unescaped.ends_with('}');
+        if unescaped.contains('{') && !(is_placeholder && unescaped.matches('{').count() == 1) {
+            return Err(syn::Error::new_spanned(
+                template,
+                format!(
+                    "placeholders of the resource path must be whole path segments (e.g. \
+                     `/cakes/{{id}}`), not `{segment}`"
+                ),
+            ));
+        }
+    }
+    let field_idents = names
+        .iter()
+        .map(|name| field(name))
+        .collect::<syn::Result<_>>()?;
+    Ok(Location {
+        template: template.clone(),
+        field_idents,
+        primary_key: None,
+        alternate_key: None,
+    })
+}
+
+/// Method to derive a `Resource` from a `Model` or `Entity` definition.
+pub fn expand_derive_resource(input: syn::DeriveInput) -> syn::Result<TokenStream> {
+    Ok(DeriveResource::new(input)?.expand())
+}
@@ -1,4 +1,116 @@
-use syn::Attribute;
+use heck::{
+    ToKebabCase, ToLowerCamelCase, ToShoutyKebabCase, ToShoutySnakeCase, ToSnakeCase,
+    ToUpperCamelCase,
+};
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{
+    punctuated::Punctuated, token::Comma, Attribute, Data, DataStruct, Field, Fields, FieldsNamed,
+    GenericArgument, GenericParam, Generics, Ident, Lit, LitStr, Meta, NestedMeta, Path,
+    PathArguments, Type,
+};
+
+use crate::attributes::derive_attr::{DerivedStruct, Skipper};
+
+/// Paths of the `sea_orm` & `sea_skipper` crates in generated code.
+pub(crate) struct CratePaths {
+    /// `#[skipper(sea_orm_crate = "...")]` (defaults to `::sea_orm`).
+    pub sea_orm: Path,
+    /// `#[skipper(crate = "...")]` (defaults to `::sea_skipper`).
+    pub sea_skipper: Path,
+}
+
+impl CratePaths {
+    pub(crate) fn new(skipper: &Skipper) -> Self {
+        Self {
+            sea_orm: skipper
+                .sea_orm_crate
+                .clone()
+                .unwrap_or_else(|| syn::parse_quote!(::sea_orm)),
+            sea_skipper: skipper
+                .krate
+                .clone()
+                .unwrap_or_else(|| syn::parse_quote!(::sea_skipper)),
+        }
+    }
+
+    /// Returns `#[skipper(...)]` attributes with the crate paths that were overridden (if any), to
+    /// carry them over to a derived struct deriving `sea_skipper` macros.
+    pub(crate) fn overrides(skipper: &Skipper) -> Option<Attribute> {
+        let mut items: Vec<TokenStream> = Vec::new();
+        if let Some(path) = &skipper.krate {
+            let path = path.to_token_stream().to_string();
+            items.push(quote!(crate = #path));
+        }
+        if let Some(path) = &skipper.sea_orm_crate {
+            let path = path.to_token_stream().to_string();
+            items.push(quote!(sea_orm_crate = #path));
+        }
+        if items.is_empty() {
+            None
+        } else {
+            Some(syn::parse_quote!(#[skipper(#(#items),*)]))
+        }
+    }
+}
+
+/// Returns the named fields of a struct's `data`, or an error pointing at the input of the derive
+/// macro `derive` if it is not a struct with named fields (`ident` is the input's ident).
+pub(crate) fn named_fields(
+    ident: &Ident,
+    data: Data,
+    derive: &str,
+) -> syn::Result<Punctuated<Field, Comma>> {
+    let (span, kind) = match data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(FieldsNamed { named, .. }),
+            ..
+        }) => return Ok(named),
+        Data::Struct(DataStruct {
+            fields: Fields::Unnamed(fields),
+            ..
+        }) => (fields.paren_token.span, "tuple structs"),
+        Data::Struct(DataStruct {
+            fields: Fields::Unit,
+            ..
+        }) => (ident.span(), "unit structs"),
+        Data::Enum(data) => (data.enum_token.span, "enums"),
+        Data::Union(data) => (data.union_token.span, "unions"),
+    };
+    Err(syn::Error::new(
+        span,
+        format!("`{derive}` can only be derived on structs with named fields, not {kind}"),
+    ))
+}
+
+/// Returns the params of `generics` without defaults (e.g. for `impl<...>`) & their arguments
+/// (e.g. for `Type<...>`), so that more params & arguments can be added to them.
+pub(crate) fn generic_params(generics: &Generics) -> (Vec<TokenStream>, Vec<TokenStream>) {
+    generics
+        .params
+        .iter()
+        .map(|param| match param {
+            GenericParam::Type(param) => {
+                let mut param = param.clone();
+                param.eq_token = None;
+                param.default = None;
+                let ident = &param.ident;
+                (quote!(#param), quote!(#ident))
+            }
+            GenericParam::Lifetime(param) => {
+                let lifetime = &param.lifetime;
+                (quote!(#param), quote!(#lifetime))
+            }
+            GenericParam::Const(param) => {
+                let mut param = param.clone();
+                param.eq_token = None;
+                param.default = None;
+                let ident = &param.ident;
+                (quote!(#param), quote!(#ident))
+            }
+        })
+        .unzip()
+}
 
 /// Returns true if `attr` is not a `#[sea_orm(...)]` attribute.
 pub(crate) fn not_sea_orm_attr(attr: &Attribute) -> bool {
@@ -10,6 +122,348 @@ pub(crate) fn not_sea_orm_attr(attr: &Attribute) -> bool {
     true
 }
 
+/// Returns true if `attr` is not a `#[skipper(...)]` attribute.
+pub(crate) fn not_skipper_attr(attr: &Attribute) -> bool {
+    !attr.path.is_ident("skipper")
+}
+
+/// Returns the `Column` variant ident for a field named `field_ident` (without `enum_name`).
+pub(crate) fn column_ident(field_ident: &Ident) -> Ident {
+    let field_name = trim_starting_raw_identifier(field_ident).to_upper_camel_case();
+    Ident::new(&escape_rust_keyword(field_name), Span::call_site())
+}
+
+/// Returns true if `ty` is an `Option<T>` (matched by the last path segment, e.g. `std::option::Option`).
+pub(crate) fn is_option_type(ty: &Type) -> bool {
+    if let Type::Path(type_path) = ty {
+        if let Some(segment) = type_path.path.segments.last() {
+            return segment.ident == "Option";
+        }
+    }
+    false
+}
+
+/// Returns true if `ty` is a `String` or an `Option<String>` (matched by the last path segment).
+pub(crate) fn is_string_type(ty: &Type) -> bool {
+    if let Type::Path(type_path) = option_inner_type(ty).unwrap_or(ty) {
+        if let Some(segment) = type_path.path.segments.last() {
+            return segment.ident == "String";
+        }
+    }
+    false
+}
+
+/// Returns `T` if `ty` is an `Option<T>` (matched by the last path segment like [`is_option_type`]).
+pub(crate) fn option_inner_type(ty: &Type) -> Option<&Type> {
+    if let Type::Path(type_path) = ty {
+        if let Some(segment) = type_path.path.segments.last() {
+            if segment.ident != "Option" {
+                return None;
+            }
+            if let PathArguments::AngleBracketed(args) = &segment.arguments {
+                if let Some(GenericArgument::Type(inner)) = args.args.first() {
+                    return Some(inner);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Returns the first type in `ty` (e.g. `f64` in `Option<f64>`) that does not implement `Eq` (i.e.
+/// floats and JSON values), if any.
+pub(crate) fn non_eq_type(ty: &Type) -> Option<&Type> {
+    match ty {
+        Type::Path(type_path) => {
+            let segments = &type_path.path.segments;
+            let last = segments.last()?;
+            let is_json_value = last.ident == "Value"
+                && segments.iter().any(|segment| segment.ident == "serde_json");
+            if last.ident == "f32" || last.ident == "f64" || last.ident == "Json" || is_json_value {
+                return Some(ty);
+            }
+            match &last.arguments {
+                PathArguments::AngleBracketed(args) => args.args.iter().find_map(|arg| match arg {
+                    GenericArgument::Type(ty) => non_eq_type(ty),
+                    _ => None,
+                }),
+                _ => None,
+            }
+        }
+        Type::Array(array) => non_eq_type(&array.elem),
+        Type::Group(group) => non_eq_type(&group.elem),
+        Type::Paren(paren) => non_eq_type(&paren.elem),
+        Type::Reference(reference) => non_eq_type(&reference.elem),
+        Type::Slice(slice) => non_eq_type(&slice.elem),
+        Type::Tuple(tuple) => tuple.elems.iter().find_map(non_eq_type),
+        _ => None,
+    }
+}
+
+/// Returns the derives of a `NewModel` or `PatchModel` struct named `ident`.
+///
+/// The `defaults` are extended by the `derive(...)` list of `config` (or replaced with
+/// `default_derives = false`). The `required` derives (e.g. `sea_skipper` derives that other
+/// generated code relies on) are always kept, and aren't repeated if `config` lists them too. `Eq`
+/// is left out of the `defaults` if a type in `field_types` does not implement `Eq` (see
+/// [`non_eq_type`]), and requesting `Eq`, `Ord` or `Hash` for such a struct is an error.
+pub(crate) fn struct_derives(
+    ident: &Ident,
+    config: &DerivedStruct,
+    required: Vec<Path>,
+    defaults: Vec<Path>,
+    field_types: &[&Type],
+) -> syn::Result<Vec<Path>> {
+    let non_eq = field_types.iter().find_map(|ty| non_eq_type(ty));
+
+    for path in &config.derives {
+        let Some(name) = path.segments.last().map(|segment| &segment.ident) else {
+            continue;
+        };
+        if let (Some(ty), true) = (non_eq, name == "Eq" || name == "Ord" || name == "Hash") {
+            let ty_name = quote::quote!(#ty).to_string().replace(' ', "");
+            let mut err = syn::Error::new_spanned(
+                path,
+                format!("can't derive `{name}` on `{ident}` because a field's type `{ty_name}` does not implement `{name}`"),
+            );
+            err.combine(syn::Error::new_spanned(
+                ty,
+                format!("`{ty_name}` does not implement `{name}`"),
+            ));
+            return Err(err);
+        }
+    }
+
+    let last_ident = |path: &Path| path.segments.last().map(|segment| segment.ident.clone());
+    let required_idents: Vec<_> = required.iter().map(last_ident).collect();
+    let mut derives = required;
+    if !config.replace_derives {
+        derives.extend(
+            defaults
+                .into_iter()
+                .filter(|path| non_eq.is_none() || !path.is_ident("Eq")),
+        );
+    }
+    derives.extend(
+        config
+            .derives
+            .iter()
+            .filter(|path| !required_idents.contains(&last_ident(path)))
+            .cloned(),
+    );
+    Ok(derives)
+}
+
+/// Returns true if the derives of a struct configured by `config` include a `serde` derive (see
+/// [`struct_derives`]), given whether its default derives do (`default_serde`).
+///
+/// `serde` attributes are only carried over to structs deriving `serde` traits, since they don't
+/// compile otherwise.
+pub(crate) fn derives_serde(config: &DerivedStruct, default_serde: bool) -> bool {
+    (default_serde && !config.replace_derives)
+        || config.derives.iter().any(|path| {
+            path.segments.last().is_some_and(|segment| {
+                segment.ident == "Serialize" || segment.ident == "Deserialize"
+            })
+        })
+}
+
+/// Returns the serialized name of a field named `field_ident` with `serde` field attributes in
+/// `attrs`, given the `rename_all` rule (see [`serde_rename_all`]) of its struct.
+pub(crate) fn serde_field_name(
+    field_ident: &Ident,
+    attrs: &[Attribute],
+    rename_all: Option<&str>,
+) -> String {
+    if let Some(name) = serde_attr_str(attrs, "rename") {
+        return name;
+    }
+    let name = trim_starting_raw_identifier(field_ident);
+    match rename_all {
+        Some("lowercase") => name.to_lowercase(),
+        Some("UPPERCASE") => name.to_uppercase(),
+        Some("PascalCase") => name.to_upper_camel_case(),
+        Some("camelCase") => name.to_lower_camel_case(),
+        Some("snake_case") => name.to_snake_case(),
+        Some("SCREAMING_SNAKE_CASE") => name.to_shouty_snake_case(),
+        Some("kebab-case") => name.to_kebab_case(),
+        Some("SCREAMING-KEBAB-CASE") => name.to_shouty_kebab_case(),
+        _ => name,
+    }
+}
+
+/// Returns the `rename_all` rule of `serde` struct attributes in `attrs` (if any).
+pub(crate) fn serde_rename_all(attrs: &[Attribute]) -> Option<String> {
+    serde_attr_str(attrs, "rename_all")
+}
+
+/// Returns the (serialization) value of `#[serde(name = "...")]` or
+/// `#[serde(name(serialize = "..."))]` in `attrs`.
+fn serde_attr_str(attrs: &[Attribute], name: &str) -> Option<String> {
+    let mut value = None;
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("serde")) {
+        let Ok(Meta::List(list)) = attr.parse_meta() else {
+            continue;
+        };
+        for nested in list.nested {
+            match nested {
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident(name) => {
+                    if let Lit::Str(litstr) = nv.lit {
+                        value = Some(litstr.value());
+                    }
+                }
+                NestedMeta::Meta(Meta::List(list)) if list.path.is_ident(name) => {
+                    for nested in list.nested {
+                        if let NestedMeta::Meta(Meta::NameValue(nv)) = nested {
+                            if let (true, Lit::Str(litstr)) =
+                                (nv.path.is_ident("serialize"), nv.lit)
+                            {
+                                value = Some(litstr.value());
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    value
+}
+
+/// A parsed path template of a `Location` (e.g. `#[skipper(location = "/cakes/{id}")]`).
+pub(crate) struct Location {
+    /// The path template.
+    pub template: LitStr,
+    /// Fields of the placeholders.
+    pub field_idents: Vec<Ident>,
+    /// The (single column) primary key field (and its type) whose placeholder prefers the
+    /// `alternate_key`.
+    pub primary_key: Option<(Ident, Type)>,
+    /// The `#[skipper(alternate_key)]` field preferred over the `primary_key` (if any).
+    pub alternate_key: Option<AlternateKey>,
+}
+
+/// A `#[skipper(alternate_key)]` field of a `Location`.
+pub(crate) struct AlternateKey {
+    /// Ident of the field.
+    pub field_ident: Ident,
+    /// Whether the field is an `Option` (which is only preferred when it is `Some`).
+    pub optional: bool,
+}
+
+impl Location {
+    /// Implements `Location` on the struct `ident` with `generics`.
+    ///
+    /// The path is rendered by `RouteTemplate::item_path()`, which percent-encodes field values.
+    pub(crate) fn expand(
+        &self,
+        sea_skipper: &Path,
+        ident: &impl ToTokens,
+        generics: &Generics,
+    ) -> TokenStream {
+        let Self {
+            template,
+            field_idents,
+            primary_key,
+            alternate_key,
+        } = self;
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        // Errors of placeholders (e.g. a field that does not implement `Display`) point at the
+        // path template.
+        let to_string = |field: TokenStream| quote_spanned!(template.span() => ::std::string::ToString::to_string(#field));
+        let values = field_idents.iter().map(|field_ident| {
+            let value = to_string(quote!(&self.#field_ident));
+            // The alternate key (when it is set) is preferred over the primary key, unless it is
+            // a valid primary key value (which `Resource::path_condition()` looks up by the
+            // primary key instead).
+            match (alternate_key, primary_key) {
+                (
+                    Some(AlternateKey {
+                        field_ident: alternate_ident,
+                        optional,
+                    }),
+                    Some((primary_key, primary_key_type)),
+                ) if primary_key == field_ident => {
+                    let alternate = to_string(quote!(alternate));
+                    let is_id = quote!(
+                        <#primary_key_type as #sea_skipper::IdSegment>::from_segment(alternate).is_some()
+                    );
+                    if *optional {
+                        quote!(match &self.#alternate_ident {
+                            ::std::option::Option::Some(alternate) if !#is_id => #alternate,
+                            _ => #value,
+                        })
+                    } else {
+                        quote!({
+                            let alternate = &self.#alternate_ident;
+                            if #is_id { #value } else { #alternate }
+                        })
+                    }
+                }
+                _ => value,
+            }
+        });
+
+        quote!(
+            #[automatically_derived]
+            impl #impl_generics #sea_skipper::Location for #ident #ty_generics #where_clause {
+                fn location(&self) -> ::std::string::String {
+                    #sea_skipper::RouteTemplate::new(#template)
+                        .item_path::<::std::string::String>(&[#(#values),*])
+                }
+            }
+        )
+    }
+}
+
+/// Parses the field names of the placeholders of a path template (e.g. `id` of `"/cakes/{id}"`).
+///
+/// `{{` and `}}` are literal braces.
+pub(crate) fn path_template(template: &LitStr) -> syn::Result<Vec<String>> {
+    let value = template.value();
+    let mut names = Vec::new();
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' | '}' if chars.peek() == Some(&c) => {
+                chars.next();
+            }
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => name.push(c),
+                        None => {
+                            return Err(syn::Error::new_spanned(
+                                template,
+                                "unclosed `{` in path template",
+                            ))
+                        }
+                    }
+                }
+                let name = name.trim();
+                if name.is_empty() {
+                    return Err(syn::Error::new_spanned(
+                        template,
+                        "expected a field name in `{}` of path template",
+                    ));
+                }
+                names.push(name.to_string());
+            }
+            '}' => {
+                return Err(syn::Error::new_spanned(
+                    template,
+                    "unmatched `}` in path template (use `}}` for a literal `}`)",
+                ))
+            }
+            _ => {}
+        }
+    }
+    Ok(names)
+}
+
 /// Code lifted from upstream @ sea-orm-macros::derives::utils.
 pub use sea_orm_macros_utils::*;
 mod sea_orm_macros_utils {
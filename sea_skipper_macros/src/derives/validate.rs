@@ -0,0 +1,87 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Generics, Ident, Path};
+
+use crate::attributes::field_attr::Validator;
+
+/// A field with `#[skipper(validate(...))]` validators.
+pub(crate) struct ValidatedField {
+    /// Path of the field in validation errors (i.e. its serialized name).
+    pub path: String,
+    /// Expression of type `Option<&T>` for the field's value (`None` skips validation).
+    pub value: TokenStream,
+    pub validators: Vec<Validator>,
+}
+
+/// Implements `sea_skipper::validation::Validate` on the struct `ident` validating `fields`.
+pub(crate) fn impl_validate(
+    sea_skipper: &Path,
+    ident: &Ident,
+    generics: &Generics,
+    fields: &[ValidatedField],
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let body = if fields.is_empty() {
+        quote!(::std::result::Result::Ok(()))
+    } else {
+        let field_checks = fields.iter().map(|field| {
+            let ValidatedField {
+                path,
+                value,
+                validators,
+            } = field;
+            let checks = validators
+                .iter()
+                .map(|validator| validator_call(sea_skipper, validator));
+            quote!(
+                if let ::std::option::Option::Some(value) = #value {
+                    #(errors.add(#path, #checks);)*
+                }
+            )
+        });
+        quote!(
+            let mut errors = #sea_skipper::validation::ValidationErrors::default();
+            #(#field_checks)*
+            errors.into_result()
+        )
+    };
+
+    quote!(
+        #[automatically_derived]
+        impl #impl_generics #sea_skipper::validation::Validate for #ident #ty_generics #where_clause {
+            fn validate(&self) -> ::std::result::Result<(), #sea_skipper::validation::ValidationErrors> {
+                #body
+            }
+        }
+    )
+}
+
+/// Returns a call of `validator` on `value` that returns `Result<(), ValidationError>`.
+fn validator_call(sea_skipper: &Path, validator: &Validator) -> TokenStream {
+    let bound = |bound: &Option<syn::Expr>| match bound {
+        Some(expr) => quote!(::std::option::Option::Some(#expr)),
+        None => quote!(::std::option::Option::None),
+    };
+    match validator {
+        Validator::Length { min, max } => {
+            let (min, max) = (bound(min), bound(max));
+            quote!(#sea_skipper::validation::length(value, #min, #max))
+        }
+        Validator::Range { min, max } => {
+            let (min, max) = (bound(min), bound(max));
+            quote!(#sea_skipper::validation::range(value, #min, #max))
+        }
+        Validator::Regex(pattern) => quote!(
+            #sea_skipper::validation::regex(value, {
+                static REGEX: ::std::sync::OnceLock<#sea_skipper::validation::Regex> =
+                    ::std::sync::OnceLock::new();
+                REGEX.get_or_init(|| {
+                    #sea_skipper::validation::Regex::new(#pattern)
+                        .expect("regex is compiled when deriving `Validate`")
+                })
+            })
+        ),
+        Validator::Email => quote!(#sea_skipper::validation::email(value)),
+        Validator::Custom(path) => quote!(#path(value)),
+    }
+}
@@ -0,0 +1,297 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{ext::IdentExt, Attribute, Generics, Ident, Path, Type, Visibility};
+
+use crate::attributes::{derive_attr, field_attr};
+
+use super::util::{
+    derives_serde, is_option_type, named_fields, path_template, struct_derives, AlternateKey,
+    CratePaths, Location,
+};
+
+struct ViewField {
+    attrs: Vec<Attribute>,
+    /// Ident of the field on the `ViewModel` (after `#[skipper(view_rename = "...")]`).
+    ident: Ident,
+    /// Ident of the field on the `Model`.
+    model_field_ident: Ident,
+    ty: Type,
+}
+
+struct DeriveViewModel {
+    attrs: Vec<Attribute>,
+    crate_paths: CratePaths,
+    derives: Vec<Path>,
+    generics: Generics,
+    ident: Ident,
+    vis: Visibility,
+    fields: Vec<ViewField>,
+    /// `#[skipper(location = "...")]` (if any).
+    location: Option<Location>,
+    /// Ident of the `Model` deriving `DeriveViewModel`.
+    model_ident: Ident,
+}
+
+impl DeriveViewModel {
+    fn new(input: syn::DeriveInput) -> syn::Result<Self> {
+        let model_ident = input.ident;
+        let generics = input.generics;
+
+        let fields = named_fields(&model_ident, input.data, "DeriveViewModel")?;
+
+        let skipper_attr = derive_attr::Skipper::from_attributes(&input.attrs)?;
+
+        // `serde` attributes (if the `ViewModel` derives `serde` traits) and
+        // `#[skipper(view_model_attr(...))]` are added to the `ViewModel` struct.
+        let forward_serde = derives_serde(
+            &skipper_attr.view_model,
+            cfg!(feature = "derive-viewmodel-with-serde"),
+        );
+        let is_forwarded_serde_attr =
+            |attr: &Attribute| forward_serde && attr.path.is_ident("serde");
+        let mut attrs: Vec<Attribute> = input
+            .attrs
+            .iter()
+            .filter(|attr| is_forwarded_serde_attr(attr))
+            .cloned()
+            .collect();
+        attrs.extend(
+            skipper_attr
+                .view_model_attrs
+                .iter()
+                .map(|meta| -> Attribute { syn::parse_quote!(#[#meta]) }),
+        );
+
+        let ident = skipper_attr
+            .view_model
+            .name
+            .clone()
+            .unwrap_or_else(|| format_ident!("ViewModel"));
+        let vis = skipper_attr
+            .view_model
+            .vis
+            .clone()
+            .unwrap_or_else(|| syn::parse_quote!(pub));
+
+        let mut view_fields: Vec<ViewField> = Vec::new();
+        // `Model` field names & their `ViewModel` field idents (`None` for hidden fields).
+        let mut field_names: Vec<(String, Option<Ident>)> = Vec::new();
+        // `ViewModel` field idents & types of the primary key (`None` for hidden fields).
+        let mut primary_key: Vec<Option<(Ident, Type)>> = Vec::new();
+        let mut alternate_key: Option<AlternateKey> = None;
+
+        for field in fields {
+            if let Some(model_field_ident) = &field.ident {
+                let skipper_attr = field_attr::Skipper::from_field(&field)?;
+                let is_primary_key = field_attr::SeaOrm::from_field(&field)?.primary_key;
+
+                // Write-only fields are never returned.
+                if skipper_attr.hidden || skipper_attr.write_only {
+                    field_names.push((model_field_ident.unraw().to_string(), None));
+                    if is_primary_key {
+                        primary_key.push(None);
+                    }
+                    continue;
+                }
+
+                let ident = skipper_attr
+                    .view_rename
+                    .unwrap_or_else(|| model_field_ident.clone());
+                field_names.push((model_field_ident.unraw().to_string(), Some(ident.clone())));
+                if is_primary_key {
+                    primary_key.push(Some((ident.clone(), field.ty.clone())));
+                }
+                if skipper_attr.alternate_key {
+                    if alternate_key.is_some() {
+                        return Err(syn::Error::new_spanned(
+                            model_field_ident,
+                            "only one field can be an `alternate_key`",
+                        ));
+                    }
+                    alternate_key = Some(AlternateKey {
+                        field_ident: ident.clone(),
+                        optional: is_option_type(&field.ty),
+                    });
+                }
+
+                let mut attrs: Vec<Attribute> = field
+                    .attrs
+                    .iter()
+                    .filter(|attr| is_forwarded_serde_attr(attr))
+                    .cloned()
+                    .collect();
+                attrs.extend(
+                    skipper_attr
+                        .view_model_attrs
+                        .iter()
+                        .map(|meta| -> Attribute { syn::parse_quote!(#[#meta]) }),
+                );
+
+                view_fields.push(ViewField {
+                    attrs,
+                    ident,
+                    model_field_ident: model_field_ident.clone(),
+                    ty: field.ty,
+                });
+            }
+        }
+
+        // Placeholders of the location template are `Model` fields that must be in the `ViewModel`.
+        // The path of `#[skipper(resource(path = "..."))]` is the location by default.
+        let template = skipper_attr
+            .location
+            .as_ref()
+            .or(skipper_attr.resource.path.as_ref());
+        let location = match template {
+            Some(template) => {
+                let names = path_template(template)?;
+                let mut field_idents = Vec::new();
+                for name in &names {
+                    let message = match field_names.iter().find(|(field, _)| field == name) {
+                        Some((_, Some(ident))) => {
+                            field_idents.push(ident.clone());
+                            continue;
+                        }
+                        Some((_, None)) => {
+                            format!("hidden or write-only field `{name}` can't be in the location")
+                        }
+                        None => format!("unknown field `{name}` in the location"),
+                    };
+                    return Err(syn::Error::new_spanned(template, message));
+                }
+                // The alternate key (unless hidden) is preferred over a single column primary key.
+                let (primary_key, alternate_key) = match primary_key.as_slice() {
+                    [Some(primary_key)] => (Some(primary_key.clone()), alternate_key),
+                    _ => (None, None),
+                };
+                Some(Location {
+                    template: template.clone(),
+                    field_idents,
+                    primary_key,
+                    alternate_key,
+                })
+            }
+            None => None,
+        };
+
+        let mut default_derives: Vec<Path> = vec![
+            syn::parse_quote!(Clone),
+            syn::parse_quote!(Debug),
+            syn::parse_quote!(PartialEq),
+            syn::parse_quote!(Eq),
+        ];
+        if cfg!(feature = "derive-viewmodel-with-serde") {
+            default_derives.push(syn::parse_quote!(::serde::Deserialize));
+            default_derives.push(syn::parse_quote!(::serde::Serialize));
+        }
+        let field_types: Vec<&Type> = view_fields.iter().map(|field| &field.ty).collect();
+        let derives = struct_derives(
+            &ident,
+            &skipper_attr.view_model,
+            Vec::new(),
+            default_derives,
+            &field_types,
+        )?;
+
+        Ok(DeriveViewModel {
+            attrs,
+            crate_paths: CratePaths::new(&skipper_attr),
+            derives,
+            generics,
+            ident,
+            vis,
+            fields: view_fields,
+            location,
+            model_ident,
+        })
+    }
+
+    fn expand(&self) -> syn::Result<TokenStream> {
+        let expanded_view_model_struct = self.view_model_struct();
+        let expanded_impl_from_model = self.impl_from_model();
+        let expanded_impl_location = self.impl_location();
+
+        Ok(TokenStream::from_iter([
+            expanded_view_model_struct,
+            expanded_impl_from_model,
+            expanded_impl_location,
+        ]))
+    }
+
+    fn view_model_struct(&self) -> TokenStream {
+        let Self {
+            attrs,
+            derives,
+            generics,
+            ident,
+            vis,
+            fields,
+            ..
+        } = self;
+        let where_clause = &generics.where_clause;
+
+        let field_attrs = fields.iter().map(|field| &field.attrs);
+        let field_idents = fields.iter().map(|field| &field.ident);
+        let field_types = fields.iter().map(|field| &field.ty);
+
+        quote!(
+            #[automatically_derived]
+            #[derive(#(#derives),*)]
+            #[doc = " Generated by [`sea_skipper_macros::DeriveViewModel`]"]
+            #(#attrs)*
+            #vis struct #ident #generics #where_clause {
+                #(
+                    #(#field_attrs)*
+                    #vis #field_idents: #field_types,
+                )*
+            }
+        )
+    }
+
+    /// Implements `From<Model>` on the `ViewModel` (dropping hidden fields).
+    fn impl_from_model(&self) -> TokenStream {
+        let Self {
+            generics,
+            ident,
+            fields,
+            model_ident,
+            ..
+        } = self;
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let field_idents = fields.iter().map(|field| &field.ident);
+        let model_field_idents = fields.iter().map(|field| &field.model_field_ident);
+
+        quote!(
+            #[automatically_derived]
+            impl #impl_generics ::std::convert::From<#model_ident #ty_generics> for #ident #ty_generics #where_clause {
+                fn from(model: #model_ident #ty_generics) -> Self {
+                    Self {
+                        #(#field_idents: model.#model_field_idents,)*
+                    }
+                }
+            }
+        )
+    }
+
+    /// Implements `Location` on the `ViewModel` with the `#[skipper(location = "...")]` template.
+    fn impl_location(&self) -> TokenStream {
+        let Self {
+            crate_paths: CratePaths { sea_skipper, .. },
+            generics,
+            ident,
+            location,
+            ..
+        } = self;
+
+        match location {
+            Some(location) => location.expand(sea_skipper, ident, generics),
+            None => TokenStream::new(),
+        }
+    }
+}
+
+/// Method to derive a `ViewModel` from a `Model` definition.
+pub fn expand_derive_view_model(input: syn::DeriveInput) -> syn::Result<TokenStream> {
+    DeriveViewModel::new(input)?.expand()
+}
@@ -6,9 +6,20 @@ use syn::{parse_macro_input, DeriveInput, Error};
 mod attributes;
 mod derives;
 
-/// Implements [`ModelCondition`] on a type implementing `ModelTrait`.
+/// Implements [`ModelCondition`] on a struct whose fields are columns of an entity (e.g. a `Model`
+/// or a `NewModel`).
 // TODO: Add an example and some description of [`ModelCondition`].
-#[proc_macro_derive(DeriveModelCondition, attributes(sea_orm))]
+///
+/// The entity is `Entity` (in scope where the struct is defined), or the one given with
+/// `#[sea_orm(entity = "...")]` (a path, e.g. `#[sea_orm(entity = "super::cake::Entity")]`). The
+/// struct may be generic: its generics and where clause are kept on the generated impl. See
+/// [`DeriveNewModel`] to change the paths of the `sea_orm` and `sea_skipper` crates.
+///
+/// Unique keys used by `ModelCondition::to_unique_conditions()` are read from `#[sea_orm(unique)]`
+/// and `#[skipper(unique_group = "...")]` field attributes.
+///
+/// Fields with a `#[skipper(condition = false)]` attribute are left out of all conditions.
+#[proc_macro_derive(DeriveModelCondition, attributes(sea_orm, skipper))]
 pub fn derive_model_condition(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
@@ -21,13 +32,110 @@ pub fn derive_model_condition(input: TokenStream) -> TokenStream {
 /// Derives a `NewModel` type from a `Model` and excludes the model's primary key fields.
 ///
 /// A `NewModel` is equivalent to a `Model` after removing all fields containing the
-/// `#[sea_orm(primary_key)]` attribute.
+/// `#[sea_orm(primary_key)]`, `#[skipper(skip_new)]` or `#[skipper(read_only)]` attributes.
+///
+/// `NewModel` implements `IntoActiveModel` for the `Model`'s `ActiveModel`. Excluded fields are
+/// `NotSet`, so they may be filled by column defaults or `ActiveModelBehavior` on insert. Use
+/// `#[skipper(skip_new = "...")]` to exclude a field and set its `ActiveModel` value to an
+/// expression instead (e.g. `#[skipper(skip_new = "chrono::Utc::now()")]`).
 ///
 /// [`DeriveNewModel`] implements [`ModelCondition`] on the `NewModel` type.
 ///
-/// This does not carry over field attributes from the original `Model` to the `NewModel`, so
-/// additional derives on the `NewModel` that require `sea_orm` attributes may not work as
-/// expected. (If you would like this functionality, please open an issue.)
+/// [`DeriveNewModel`] also implements `sea_skipper::NewModelTrait` on the `NewModel` to access its
+/// column values by `Column`. `NewModelTrait::columns()` lists the columns the `NewModel` carries,
+/// and `try_get()` & `try_set()` return an error for other columns (e.g. the primary key) or for a
+/// value of the wrong type. (`NewModel` does not implement `ModelTrait`, which can't fail.)
+///
+/// # Conversions
+///
+/// `NewModel` implements `From<Model>` (dropping the primary key & excluded fields), e.g. to copy
+/// an existing record. `NewModel::into_model(self, id)` converts a `NewModel` into a `Model` with
+/// the primary key `id`, and `NewModel::into_active_model_with_id(self, id)` into an `ActiveModel`
+/// with the primary key `Set` (e.g. for client-chosen IDs). The `id` of a composite primary key is
+/// a tuple of the key's fields in declaration order (e.g. `(i32, String)`).
+///
+/// `into_model()` sets fields excluded with `#[skipper(skip_new = "...")]` to their default value.
+/// It is only implemented if every excluded field (including `read_only` fields) has a default
+/// value, since there is no value for other fields until the database provides one;
+/// `into_active_model_with_id()` leaves them `NotSet` instead.
+///
+/// # Builder
+///
+/// `NewModel::builder()` returns a `NewModelBuilder` with a setter for each field (taking any value
+/// that converts `Into` the field's type), e.g.
+/// `NewModel::builder().name("Carrot").price(3).build()`. `build()` only compiles once every
+/// required field is set. `Option` fields (defaulting to `None`) and fields with
+/// `#[skipper(default)]` (defaulting to `Default::default()`) or `#[skipper(default = "...")]`
+/// (defaulting to the given expression) may be omitted.
+///
+/// # Attributes
+///
+/// `sea_orm` and `skipper` field attributes are carried over from the original `Model` to the
+/// `NewModel` (so that [`ModelCondition`] uses the same columns and unique keys as the `Model`).
+/// With the `derive-newmodel-with-serde` feature, `serde` struct and field attributes (e.g.
+/// `#[serde(rename = "...")]`, `#[serde(default)]`) are carried over too, so `NewModel` is
+/// (de)serialized like the `Model`.
+///
+/// Other attributes are dropped. Use `#[skipper(new_model_attr(...))]` on the `Model` or on a field
+/// to add attributes to the `NewModel` struct or field (e.g.
+/// `#[skipper(new_model_attr(serde(deny_unknown_fields)))]`).
+///
+/// # Name & visibility
+///
+/// The `NewModel` struct is named `NewModel` and is `pub` by default. Use
+/// `#[skipper(new_model(name = "...", vis = "..."))]` on the `Model` to change its name and
+/// visibility (e.g. `#[skipper(new_model(name = "CreateCake", vis = "pub(crate)"))]`).
+///
+/// # Derives
+///
+/// `NewModel` derives `Clone`, `Debug`, `PartialEq`, `Eq` and [`DeriveModelCondition`] (and
+/// `Deserialize` and `Serialize` with the `derive-newmodel-with-serde` feature). `Eq` is left out
+/// when a field's type does not implement it (`f32`, `f64` and JSON values, including inside
+/// `Option` or `Vec`).
+///
+/// Use `#[skipper(new_model(derive(...)))]` to add derives (e.g. `derive(Default, Hash)`), and
+/// `#[skipper(new_model(default_derives = false, derive(...)))]` to replace the default derives
+/// (except [`DeriveModelCondition`], which is always derived).
+/// Deriving `Eq`, `Ord` or `Hash` with a float or JSON field is a compile error pointing at the
+/// field.
+///
+/// `serde` attributes of the `Model` and its fields are carried over to the `NewModel` if it
+/// derives `Serialize` or `Deserialize` (the same applies to the `PatchModel` & `ViewModel`).
+///
+/// # Validation
+///
+/// [`DeriveNewModel`] implements `sea_skipper::validation::Validate` on the `NewModel` with the
+/// validators of `#[skipper(validate(...))]` field attributes:
+/// - `length(min = 1, max = 100)`: the number of characters (or items) is within bounds.
+/// - `range(min = 0, max = 100)`: the value is within bounds (a string contains an expression,
+///   e.g. `max = "MAX_PRICE"`).
+/// - `regex = "^[a-z]+$"`: the value matches a regex (requires `sea_skipper`'s `regex` feature). An
+///   invalid pattern (or one exceeding `regex`'s size limit) is a compile error.
+/// - `email`: the value is an email address.
+/// - `custom = "path::to::function"`: calls a function taking a reference to the value and returning
+///   `Result<(), sea_skipper::validation::ValidationError>`.
+///
+/// `Validate::validate()` returns an error for every failed validator. Error paths are the fields'
+/// serialized names (following `serde` `rename` and `rename_all` attributes). `None` values of
+/// `Option` fields are not validated.
+///
+/// # Paths & generics
+///
+/// Generated code refers to `::sea_orm`, `::sea_skipper` and `::serde` (for the `serde` derives)
+/// by absolute paths, so none of their items need to be in scope. Use
+/// `#[skipper(crate = "...")]` and `#[skipper(sea_orm_crate = "...")]` on the `Model` to change the
+/// paths of the `sea_skipper` and `sea_orm` crates (e.g. when they're renamed or re-exported,
+/// `#[skipper(crate = "my_api::sea_skipper")]`). These apply to all derives on the `Model` and are
+/// carried over to the `NewModel`.
+///
+/// The generated structs and impls keep the `Model`'s generics and where clause (type parameters
+/// must be used by fields that are not excluded).
+///
+/// # Unique keys
+///
+/// Fields with `#[sea_orm(unique)]` are single column unique keys. Fields with a
+/// `#[skipper(unique_group = "...")]` attribute sharing the same group name form a composite unique
+/// key. These determine the conditions returned by `ModelCondition::to_unique_conditions()`.
 ///
 /// # Usage
 ///
@@ -61,7 +169,7 @@ pub fn derive_model_condition(input: TokenStream) -> TokenStream {
 ///     pub text: String,
 /// }
 /// ````
-#[proc_macro_derive(DeriveNewModel, attributes(sea_orm))]
+#[proc_macro_derive(DeriveNewModel, attributes(sea_orm, skipper))]
 pub fn derive_new_model(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
@@ -70,3 +178,249 @@ pub fn derive_new_model(input: TokenStream) -> TokenStream {
         .into();
     ts
 }
+
+/// Derives a `PatchModel` type from a `Model` for partial updates (e.g. `PATCH` requests).
+///
+/// A `PatchModel` has the `Model`'s fields except for `#[sea_orm(primary_key)]` and
+/// `#[skipper(read_only)]` fields. Each field is optional so that a patch only contains the fields
+/// it changes:
+/// - A field of type `T` becomes an `Option<T>` (`None` leaves the column unchanged).
+/// - A nullable field of type `Option<T>` becomes a `sea_skipper::PatchValue<T>`, which
+///   distinguishes an absent field (`PatchValue::Absent`) from a field set to `null`
+///   (`PatchValue::Null`).
+///
+/// `PatchModel` implements `IntoActiveModel` for the `Model`'s `ActiveModel`. Provided fields are
+/// `Set` and every other field is `NotSet`, so updating with the `ActiveModel` only changes the
+/// provided columns. `PatchModel::is_empty()` returns true if no field is provided.
+///
+/// `PatchModel` implements `sea_skipper::PatchModelTrait`, so `PatchDocument::apply()` can turn
+/// a JSON Merge Patch or JSON Patch document into a `PatchModel` (rejecting documents changing
+/// primary key or read-only fields). Fields are named as in the `ViewModel` in patch documents
+/// (e.g. after `#[skipper(view_rename = "...")]`).
+///
+/// # Read-only & write-only fields
+///
+/// A `#[skipper(read_only)]` field (e.g. `created_at`) is returned but never accepted: it's left out
+/// of the `NewModel` and `PatchModel`. A `#[skipper(write_only)]` field (e.g. `password`) is
+/// accepted but never returned: it's left out of the `ViewModel` (see [`DeriveViewModel`]).
+///
+/// Clients never see the value of a write-only field, so patching it to `null` clears it. A
+/// write-only field of type `T` (not `Option<T>`) becomes a `PatchValue<T>` that is reset to its
+/// `#[skipper(default)]` (or `#[skipper(default = "...")]`) value when patched to `null`; without
+/// a default it's a compile error.
+///
+/// # Attributes
+///
+/// With the `derive-patchmodel-with-serde` feature, `PatchModel` derives `serde::Deserialize` and
+/// `Serialize`. Absent fields are skipped when serializing. The `rename`, `rename_all` and
+/// `deny_unknown_fields` struct attributes and the `rename` and `alias` field attributes of `serde`
+/// are carried over from the `Model` (other `serde` attributes may not apply to optional fields).
+///
+/// Use `#[skipper(patch_model_attr(...))]` on the `Model` or on a field to add attributes to the
+/// `PatchModel` struct or field.
+///
+/// # Derives
+///
+/// `PatchModel` derives `Clone`, `Debug`, `Default`, `PartialEq` and `Eq` (and `Deserialize` and
+/// `Serialize` with the `derive-patchmodel-with-serde` feature). Like [`DeriveNewModel`], `Eq` is
+/// left out for float and JSON fields, and `#[skipper(patch_model(derive(...)))]` and
+/// `#[skipper(patch_model(default_derives = false))]` add or replace derives.
+///
+/// # Validation
+///
+/// `PatchModel` implements `sea_skipper::validation::Validate` with the validators of
+/// `#[skipper(validate(...))]` field attributes (see [`DeriveNewModel`]). Absent fields and `null`
+/// values of nullable fields are not validated.
+///
+/// # Name & visibility
+///
+/// The `PatchModel` struct is named `PatchModel` and is `pub` by default. Use
+/// `#[skipper(patch_model(name = "...", vis = "..."))]` on the `Model` to change its name and
+/// visibility.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, DerivePatchModel)]
+/// #[sea_orm(table_name = "posts")]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     pub title: String,
+///     pub subtitle: Option<String>,
+/// }
+/// ```
+///
+/// This expands to a `PatchModel` behind the scenes:
+///
+/// ```ignore
+/// #[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+/// pub struct PatchModel {
+///     #[serde(default, skip_serializing_if = "Option::is_none")]
+///     pub title: Option<String>,
+///     #[serde(default, skip_serializing_if = "sea_skipper::PatchValue::is_absent")]
+///     pub subtitle: sea_skipper::PatchValue<String>,
+/// }
+/// ```
+#[proc_macro_derive(DerivePatchModel, attributes(sea_orm, skipper))]
+pub fn derive_patch_model(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let ts: TokenStream = derives::expand_derive_patch_model(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into();
+    ts
+}
+
+/// Derives a `ViewModel` type from a `Model` for API responses, excluding hidden fields.
+///
+/// A `ViewModel` has the `Model`'s fields except for fields with a `#[skipper(hidden)]` or
+/// `#[skipper(write_only)]` attribute (e.g. a `password_hash` column), so it can be serialized in
+/// responses without leaking them.
+/// `ViewModel` implements `From<Model>`. Declare it as the `View` of a `sea_skipper::Resource`
+/// for handlers to respond with it instead of the `Model`.
+///
+/// # Attributes
+///
+/// Use `#[skipper(view_rename = "...")]` on a field to rename it on the `ViewModel`. With the
+/// `derive-viewmodel-with-serde` feature, `serde` struct and field attributes are carried over from
+/// the `Model`, so the `ViewModel` is serialized like the `Model`.
+///
+/// Use `#[skipper(view_model_attr(...))]` on the `Model` or on a field to add attributes to the
+/// `ViewModel` struct or field.
+///
+/// # Location
+///
+/// `#[skipper(location = "/cakes/{id}")]` on the `Model` implements `sea_skipper::Location` on the
+/// `ViewModel`. Each `{field}` of the path template is replaced with the percent-encoded value of
+/// the `Model`'s field (which can't be hidden or write-only), and `{{` and `}}` are literal braces
+/// (see `sea_skipper::RouteTemplate`). The `#[skipper(alternate_key)]` field (see
+/// [`DeriveResource`]), unless hidden, is preferred over a single column primary key when its value
+/// is not a valid primary key (the primary key's type must implement `sea_skipper::IdSegment`
+/// to check). Without a `location`, the path of `#[skipper(resource(path = "..."))]` (see
+/// [`DeriveResource`]) is used.
+///
+/// # Derives
+///
+/// `ViewModel` derives `Clone`, `Debug`, `PartialEq` and `Eq` (and `Deserialize` and `Serialize`
+/// with the `derive-viewmodel-with-serde` feature). Like [`DeriveNewModel`], `Eq` is left out for
+/// float and JSON fields, and `#[skipper(view_model(derive(...)))]` and
+/// `#[skipper(view_model(default_derives = false))]` add or replace derives.
+///
+/// # Name & visibility
+///
+/// The `ViewModel` struct is named `ViewModel` and is `pub` by default. Use
+/// `#[skipper(view_model(name = "...", vis = "..."))]` on the `Model` to change its name and
+/// visibility.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveViewModel)]
+/// #[sea_orm(table_name = "users")]
+/// #[skipper(location = "/users/{id}")]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     #[skipper(view_rename = "username")]
+///     pub name: String,
+///     #[skipper(hidden)]
+///     pub password_hash: String,
+/// }
+/// ```
+///
+/// This expands to a `ViewModel` behind the scenes:
+///
+/// ```ignore
+/// #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+/// pub struct ViewModel {
+///     pub id: i32,
+///     pub username: String,
+/// }
+/// ```
+#[proc_macro_derive(DeriveViewModel, attributes(skipper))]
+pub fn derive_view_model(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let ts: TokenStream = derives::expand_derive_view_model(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into();
+    ts
+}
+
+/// Implements `sea_skipper::Resource` for an entity, inferring its associated types.
+///
+/// Derive it on a `Model` (next to `DeriveEntityModel`) to implement `Resource` on its `Entity`
+/// (or the entity given with `#[sea_orm(entity = "...")]`):
+/// - `ActiveModel` is `ActiveModel` (or the one given with `#[sea_orm(active_model = "...")]`).
+/// - `Data` is the `Model`.
+/// - `Id` is the type of the `#[sea_orm(primary_key)]` field, or a tuple of the primary key fields'
+///   types (in declaration order) for a composite primary key. Ids of up to 12 columns can be
+///   parsed from URL path segments with `sea_skipper::ResourceId`.
+///
+/// It can also be derived on a hand-written `Entity` (a unit struct deriving `DeriveEntity`), in
+/// which case `Data` is `Model` (or the one given with `#[sea_orm(model = "...")]`) and `Id` is
+/// the `ValueType` of the entity's primary key.
+///
+/// # Attributes
+///
+/// `#[skipper(resource(path = "/cakes/{id}"))]` implements `sea_skipper::ResourceRoute` on the
+/// entity with the path template as its `RouteTemplate`, which builds the routes of the resource's
+/// collection (e.g. `/cakes`) and items (e.g. `/cakes/:id`). Placeholders must be whole path
+/// segments. It also implements `sea_skipper::Location` on the `Model` with the template (like
+/// `#[skipper(location = "...")]` of [`DeriveViewModel`], which also uses the resource's path by
+/// default).
+///
+/// `#[skipper(resource(view = "..."))]` sets the `View` type of the resource (e.g. a `ViewModel`
+/// derived with [`DeriveViewModel`]), which defaults to the `Model`.
+///
+/// `#[skipper(alternate_key)]` on a `#[sea_orm(unique)]` `String` (or `Option<String>`) field of
+/// the `Model` (e.g. a `slug`) implements `Resource::alternate_key()`, so a path segment that isn't
+/// a valid `Id` is looked up by the field instead (e.g. `/cakes/chocolate-fudge`). Only one field
+/// can be the alternate key. The `Location` prefers the alternate key when it is set (i.e. not
+/// `None`) over a single column primary key in the resource's path, unless its value is a valid
+/// `Id` (e.g. a `"42"` slug with an integer primary key), which would be looked up by the primary
+/// key instead.
+///
+/// # Usage
+///
+/// ```ignore
+/// #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveResource, DeriveViewModel)]
+/// #[sea_orm(table_name = "cake")]
+/// #[skipper(resource(path = "/cakes/{id}", view = "ViewModel"))]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     pub name: String,
+/// }
+/// ```
+///
+/// This expands to the following impls behind the scenes:
+///
+/// ```ignore
+/// impl sea_skipper::Resource for Entity {
+///     type ActiveModel = ActiveModel;
+///     type Data = Model;
+///     type View = ViewModel;
+///     type Id = i32;
+/// }
+///
+/// impl sea_skipper::ResourceRoute for Entity {
+///     const ROUTE: sea_skipper::RouteTemplate = sea_skipper::RouteTemplate::new("/cakes/{id}");
+/// }
+///
+/// impl sea_skipper::Location for Model {
+///     fn location(&self) -> String {
+///         sea_skipper::RouteTemplate::new("/cakes/{id}").item_path(&[self.id.to_string()])
+///     }
+/// }
+/// ```
+#[proc_macro_derive(DeriveResource, attributes(sea_orm, skipper))]
+pub fn derive_resource(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let ts: TokenStream = derives::expand_derive_resource(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into();
+    ts
+}
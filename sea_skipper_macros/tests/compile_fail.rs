@@ -0,0 +1,8 @@
+/// Checks the errors of invalid derive macro inputs against the expected output in `tests/ui`.
+///
+/// Run with `TRYBUILD=overwrite` to update the expected output after changing a diagnostic.
+#[test]
+fn compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
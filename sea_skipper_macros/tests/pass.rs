@@ -0,0 +1,6 @@
+/// Checks that valid derive macro inputs in `tests/pass` compile, and runs their `main` functions.
+#[test]
+fn pass() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/pass/*.rs");
+}
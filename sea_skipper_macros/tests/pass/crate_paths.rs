@@ -0,0 +1,56 @@
+//! `#[skipper(crate = "...", sea_orm_crate = "...")]` change the crate paths of generated code.
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Re-exports of the crates under other names.
+mod api {
+    pub use sea_orm as orm;
+    pub use sea_skipper as skipper;
+}
+
+use api::skipper::{
+    DeriveModelCondition, DeriveNewModel, DerivePatchModel, DeriveResource, DeriveViewModel,
+    ModelCondition, Resource,
+};
+
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    DeriveEntityModel,
+    DeriveModelCondition,
+    DeriveNewModel,
+    DerivePatchModel,
+    DeriveResource,
+    DeriveViewModel,
+    Deserialize,
+    Serialize,
+)]
+#[sea_orm(table_name = "cake")]
+#[skipper(
+    crate = "api::skipper",
+    sea_orm_crate = "api::orm",
+    resource(path = "/cakes/{id}")
+)]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    #[sea_orm(unique)]
+    pub name: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+fn main() {
+    let new_model = NewModel {
+        name: "Pancake".to_owned(),
+    };
+    assert_eq!(new_model.unique_conditions().len(), 1);
+    assert_eq!(new_model.into_model(1).name, "Pancake");
+    assert!(Entity::find_by_path_id(&["1"]).is_ok());
+    assert_eq!(PatchModel::default().name, None);
+}
@@ -0,0 +1,80 @@
+//! `DeriveModelCondition` on generic structs filtering an entity given by a path.
+use sea_orm::{entity::prelude::*, Condition, DbBackend, QueryTrait};
+use sea_skipper::{DeriveModelCondition, ModelCondition};
+
+mod bakery {
+    pub mod cake {
+        use sea_orm::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+        #[sea_orm(table_name = "cake")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub name: String,
+            pub note: Option<String>,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+}
+
+/// The entity path is given as a string.
+#[derive(DeriveModelCondition)]
+#[sea_orm(entity = "bakery::cake::Entity")]
+pub struct CakeFilter<N>
+where
+    N: Into<Value> + Clone,
+{
+    #[sea_orm(unique)]
+    pub name: N,
+    pub note: Option<String>,
+}
+
+/// The entity path is given without quotes.
+#[derive(DeriveModelCondition)]
+#[sea_orm(entity = self::bakery::cake::Entity)]
+pub struct NameFilter<'a, N: Into<Value> + Clone = String> {
+    pub name: N,
+    #[skipper(condition = false)]
+    pub label: &'a str,
+}
+
+fn filter<M: ModelCondition>(condition: Condition) -> String {
+    let sql = M::Entity::find()
+        .filter(condition)
+        .build(DbBackend::Postgres)
+        .to_string();
+    sql[sql.find("WHERE").unwrap()..].to_owned()
+}
+
+fn main() {
+    let cake_filter = CakeFilter {
+        name: "Pancake",
+        note: None,
+    };
+    assert_eq!(
+        filter::<CakeFilter<&str>>(cake_filter.all_condition()),
+        r#"WHERE "cake"."name" = 'Pancake' AND "cake"."note" IS NULL"#
+    );
+    assert_eq!(
+        cake_filter
+            .unique_conditions()
+            .into_iter()
+            .map(filter::<CakeFilter<&str>>)
+            .collect::<Vec<_>>(),
+        [r#"WHERE "cake"."name" = 'Pancake'"#]
+    );
+
+    let name_filter: NameFilter = NameFilter {
+        name: "Pancake".to_owned(),
+        label: "pancakes",
+    };
+    assert_eq!(
+        filter::<NameFilter>(name_filter.to_all_condition()),
+        r#"WHERE "cake"."name" = 'Pancake'"#
+    );
+}
@@ -0,0 +1,66 @@
+//! Conditions of `DeriveModelCondition` compare `None` fields with `IS NULL` or skip them.
+use sea_orm::{entity::prelude::*, Condition, DbBackend, QueryTrait};
+use sea_skipper::{DeriveModelCondition, ModelCondition};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveModelCondition)]
+#[sea_orm(table_name = "cake")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    #[sea_orm(unique)]
+    pub name: String,
+    pub note: Option<String>,
+    #[skipper(condition = false)]
+    pub updated_at: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+fn filter(condition: Condition) -> String {
+    let sql = Entity::find()
+        .filter(condition)
+        .build(DbBackend::Postgres)
+        .to_string();
+    sql[sql.find("WHERE").unwrap()..].to_owned()
+}
+
+fn main() {
+    let model = Model {
+        id: 1,
+        name: "Pancake".to_owned(),
+        note: None,
+        updated_at: 0,
+    };
+    assert_eq!(
+        filter(model.all_condition()),
+        r#"WHERE "cake"."id" = 1 AND "cake"."name" = 'Pancake' AND "cake"."note" IS NULL"#
+    );
+    assert_eq!(
+        filter(model.any_condition()),
+        r#"WHERE "cake"."id" = 1 OR "cake"."name" = 'Pancake'"#
+    );
+    assert_eq!(
+        filter(model.partial_condition()),
+        r#"WHERE "cake"."id" = 1 AND "cake"."name" = 'Pancake'"#
+    );
+
+    let model = Model {
+        note: Some("fluffy".to_owned()),
+        ..model
+    };
+    assert_eq!(
+        filter(model.all_condition()),
+        r#"WHERE "cake"."id" = 1 AND "cake"."name" = 'Pancake' AND "cake"."note" = 'fluffy'"#
+    );
+    assert_eq!(
+        model
+            .unique_conditions()
+            .into_iter()
+            .map(filter)
+            .collect::<Vec<_>>(),
+        [r#"WHERE "cake"."name" = 'Pancake'"#]
+    );
+}
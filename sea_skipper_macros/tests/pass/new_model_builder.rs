@@ -0,0 +1,55 @@
+//! `NewModel::builder()` requires fields without defaults and lets others be omitted.
+use sea_orm::entity::prelude::*;
+use sea_skipper::DeriveNewModel;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveNewModel)]
+#[sea_orm(table_name = "cake")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub name: String,
+    pub price: i32,
+    pub note: Option<String>,
+    #[skipper(default)]
+    pub gluten_free: bool,
+    #[skipper(default = "\"plain\".to_owned()")]
+    pub flavor: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+fn main() {
+    // Setters may be called in any order and take values converting into the field's type.
+    let new_model = NewModel::builder().price(3).name("Pancake").build();
+    assert_eq!(
+        new_model,
+        NewModel {
+            name: "Pancake".to_owned(),
+            price: 3,
+            note: None,
+            gluten_free: false,
+            flavor: "plain".to_owned(),
+        }
+    );
+
+    let new_model = NewModel::builder()
+        .name("Crepe")
+        .price(4)
+        .note(Some("thin".to_owned()))
+        .gluten_free(true)
+        .flavor("lemon")
+        .build();
+    assert_eq!(
+        new_model,
+        NewModel {
+            name: "Crepe".to_owned(),
+            price: 4,
+            note: Some("thin".to_owned()),
+            gluten_free: true,
+            flavor: "lemon".to_owned(),
+        }
+    );
+}
@@ -0,0 +1,44 @@
+//! Conversions between a `Model` with a composite primary key and its `NewModel`.
+use sea_orm::{entity::prelude::*, ActiveValue};
+use sea_skipper::DeriveNewModel;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveNewModel)]
+#[sea_orm(table_name = "cake")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub bakery_id: i32,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub slug: String,
+    pub name: String,
+    #[skipper(skip_new = "7")]
+    pub version: i32,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+fn main() {
+    let new_model = NewModel {
+        name: "Pancake".to_owned(),
+    };
+
+    let model = new_model.clone().into_model((1, "pancake".to_owned()));
+    assert_eq!(
+        model,
+        Model {
+            bakery_id: 1,
+            slug: "pancake".to_owned(),
+            name: "Pancake".to_owned(),
+            version: 7,
+        }
+    );
+    assert_eq!(NewModel::from(model), new_model);
+
+    let active_model = new_model.into_active_model_with_id((2, "crepe".to_owned()));
+    assert_eq!(active_model.bakery_id, ActiveValue::Set(2));
+    assert_eq!(active_model.slug, ActiveValue::Set("crepe".to_owned()));
+    assert_eq!(active_model.name, ActiveValue::Set("Pancake".to_owned()));
+    assert_eq!(active_model.version, ActiveValue::Set(7));
+}
@@ -0,0 +1,57 @@
+//! `#[skipper(new_model(name = "...", vis = "..."))]` renames the `NewModel` and restricts it.
+use sea_orm::{DbBackend, EntityTrait, IntoActiveModel, QueryFilter, QueryTrait};
+use sea_skipper::ModelCondition;
+
+mod cake {
+    use sea_orm::entity::prelude::*;
+    use sea_skipper::DeriveNewModel;
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveNewModel)]
+    #[sea_orm(table_name = "cake")]
+    #[skipper(new_model(name = "CreateCake", vis = "pub(crate)"))]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        #[sea_orm(unique)]
+        pub name: String,
+        pub note: Option<String>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+
+    /// Another derived input type in the same module doesn't conflict with the `NewModel`.
+    #[allow(dead_code)]
+    pub struct NewModel;
+}
+
+use cake::CreateCake;
+
+fn main() {
+    let create_cake: CreateCake = CreateCake::builder().name("Pancake").build();
+    assert_eq!(
+        create_cake,
+        CreateCake {
+            name: "Pancake".to_owned(),
+            note: None,
+        }
+    );
+
+    let sql = cake::Entity::find()
+        .filter(create_cake.partial_condition())
+        .build(DbBackend::Postgres)
+        .to_string();
+    assert!(sql.ends_with(r#"WHERE "cake"."name" = 'Pancake'"#));
+
+    let active_model: cake::ActiveModel = create_cake.clone().into_active_model();
+    assert_eq!(
+        active_model.name,
+        sea_orm::ActiveValue::Set("Pancake".to_owned())
+    );
+
+    let model = create_cake.into_model(1);
+    assert_eq!(model.id, 1);
+    assert_eq!(CreateCake::from(model).name, "Pancake");
+}
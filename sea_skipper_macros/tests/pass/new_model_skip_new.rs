@@ -0,0 +1,39 @@
+//! `#[skipper(skip_new)]` fields are left out of the `NewModel`, and `NotSet` or `Set` to their
+//! default in its `ActiveModel`.
+use sea_orm::{entity::prelude::*, ActiveValue, IntoActiveModel};
+use sea_skipper::{DeriveNewModel, NewModelTrait};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveNewModel)]
+#[sea_orm(table_name = "cake")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub name: String,
+    #[skipper(skip_new)]
+    pub created_at: i64,
+    #[skipper(skip_new = "1")]
+    pub version: i32,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+fn main() {
+    let new_model = NewModel {
+        name: "Pancake".to_owned(),
+    };
+    assert!(matches!(NewModel::columns(), [Column::Name]));
+
+    let active_model = new_model.clone().into_active_model();
+    assert_eq!(active_model.id, ActiveValue::NotSet);
+    assert_eq!(active_model.name, ActiveValue::Set("Pancake".to_owned()));
+    assert_eq!(active_model.created_at, ActiveValue::NotSet);
+    assert_eq!(active_model.version, ActiveValue::Set(1));
+
+    let active_model = new_model.into_active_model_with_id(7);
+    assert_eq!(active_model.id, ActiveValue::Set(7));
+    assert_eq!(active_model.created_at, ActiveValue::NotSet);
+    assert_eq!(active_model.version, ActiveValue::Set(1));
+}
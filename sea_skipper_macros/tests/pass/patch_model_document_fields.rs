@@ -0,0 +1,45 @@
+//! `PatchModelTrait::document_fields()` names fields as in the `ViewModel`.
+use sea_orm::entity::prelude::*;
+use sea_skipper::{DerivePatchModel, PatchModelTrait};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, DerivePatchModel, Deserialize, Serialize)]
+#[sea_orm(table_name = "account")]
+#[serde(rename_all = "camelCase")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub display_name: String,
+    #[skipper(view_rename = "login")]
+    pub email: String,
+    #[serde(rename = "bio")]
+    pub biography: Option<String>,
+    #[skipper(write_only)]
+    pub password_hash: Option<String>,
+    #[skipper(hidden)]
+    pub internal_note: Option<String>,
+    #[skipper(read_only)]
+    pub created_at: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+fn main() {
+    assert_eq!(
+        PatchModel::document_fields(),
+        [
+            ("displayName", "displayName"),
+            ("login", "email"),
+            ("bio", "bio"),
+            ("passwordHash", "passwordHash"),
+            ("internalNote", "internalNote"),
+        ]
+    );
+    assert!(matches!(
+        PatchModel::read_only_columns(),
+        [Column::CreatedAt]
+    ));
+}
@@ -0,0 +1,82 @@
+//! The `Location` prefers the alternate key, unless it would be looked up as a primary key.
+use sea_orm::entity::prelude::*;
+use sea_skipper::{DeriveResource, DeriveViewModel, Location};
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    DeriveEntityModel,
+    DeriveResource,
+    DeriveViewModel,
+    Deserialize,
+    Serialize,
+)]
+#[sea_orm(table_name = "cake")]
+#[skipper(resource(path = "/cakes/{id}", view = "ViewModel"))]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    #[sea_orm(unique)]
+    #[skipper(alternate_key)]
+    pub slug: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+/// A non-optional alternate key of a `ViewModel` with its own location.
+mod tag {
+    use sea_orm::entity::prelude::*;
+    use sea_skipper::DeriveViewModel;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(
+        Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveViewModel, Deserialize, Serialize,
+    )]
+    #[sea_orm(table_name = "tag")]
+    #[skipper(location = "/tags/{id}")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i64,
+        #[sea_orm(unique)]
+        #[skipper(alternate_key)]
+        pub name: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+fn cake(id: i32, slug: Option<&str>) -> Model {
+    Model {
+        id,
+        slug: slug.map(ToOwned::to_owned),
+    }
+}
+
+fn tag(id: i64, name: &str) -> tag::ViewModel {
+    tag::Model {
+        id,
+        name: name.to_owned(),
+    }
+    .into()
+}
+
+fn main() {
+    assert_eq!(cake(1, Some("fudge")).location(), "/cakes/fudge");
+    assert_eq!(cake(1, None).location(), "/cakes/1");
+    // `/cakes/42` would be the cake whose id is 42.
+    assert_eq!(cake(1, Some("42")).location(), "/cakes/1");
+    assert_eq!(ViewModel::from(cake(1, Some("42"))).location(), "/cakes/1");
+    assert_eq!(ViewModel::from(cake(1, Some("4 2"))).location(), "/cakes/4%202");
+
+    assert_eq!(tag(1, "vegan").location(), "/tags/vegan");
+    assert_eq!(tag(1, "-7").location(), "/tags/1");
+}
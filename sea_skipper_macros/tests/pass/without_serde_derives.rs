@@ -0,0 +1,56 @@
+//! `serde` attributes of the `Model` aren't carried over to derived structs without `serde` derives.
+use sea_orm::{entity::prelude::*, IntoActiveModel};
+use sea_skipper::{DeriveNewModel, DerivePatchModel, DeriveViewModel};
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    DeriveEntityModel,
+    DeriveNewModel,
+    DerivePatchModel,
+    DeriveViewModel,
+    Deserialize,
+    Serialize,
+)]
+#[sea_orm(table_name = "cake")]
+#[serde(rename_all = "camelCase")]
+#[skipper(
+    new_model(default_derives = false, derive(Clone)),
+    patch_model(default_derives = false, derive(Clone, Default)),
+    view_model(default_derives = false, derive(Clone))
+)]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    #[serde(rename = "cakeName")]
+    pub name: String,
+    #[serde(default)]
+    pub created_at: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+fn main() {
+    let new_model = NewModel {
+        name: "Pancake".to_owned(),
+        created_at: 0,
+    };
+    let active_model = new_model.clone().into_active_model();
+    assert_eq!(active_model.name, sea_orm::ActiveValue::Set("Pancake".to_owned()));
+
+    let patch_model = PatchModel::default();
+    assert!(patch_model.clone().name.is_none());
+
+    let _view_model = ViewModel::from(Model {
+        id: 1,
+        name: "Pancake".to_owned(),
+        created_at: 0,
+    })
+    .clone();
+}
@@ -0,0 +1,22 @@
+use sea_orm::entity::prelude::*;
+use sea_skipper_macros::DeriveNewModel;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveNewModel)]
+#[sea_orm(table_name = "cake")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub name: String,
+    pub price: i32,
+    pub note: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+fn main() {
+    // `price` is required.
+    let _new_model = NewModel::builder().name("Pancake").build();
+}
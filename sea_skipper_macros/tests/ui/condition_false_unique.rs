@@ -0,0 +1,10 @@
+use sea_skipper_macros::DeriveModelCondition;
+
+#[derive(DeriveModelCondition)]
+pub struct Filter {
+    #[sea_orm(unique)]
+    #[skipper(condition = false)]
+    pub name: String,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use sea_skipper_macros::DeriveNewModel;
+
+#[derive(DeriveNewModel)]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    #[skipper(default)]
+    #[skipper(default = "String::from(\"cake\")")]
+    pub name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use sea_skipper_macros::DeriveViewModel;
+
+#[derive(DeriveViewModel)]
+#[skipper(location = "/cakes/{id}", location = "/pies/{id}")]
+pub struct Model {
+    pub id: i32,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use sea_skipper_macros::DeriveViewModel;
+
+#[derive(DeriveViewModel)]
+pub enum Model {
+    Cake { id: i32 },
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use sea_skipper_macros::DeriveViewModel;
+
+#[derive(DeriveViewModel)]
+pub struct Model {
+    pub id: i32,
+    #[skipper(hidden, view_rename = "hash")]
+    pub password_hash: String,
+}
+
+fn main() {}
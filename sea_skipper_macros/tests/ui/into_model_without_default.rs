@@ -0,0 +1,25 @@
+use sea_orm::entity::prelude::*;
+use sea_skipper_macros::DeriveNewModel;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveNewModel)]
+#[sea_orm(table_name = "cake")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub name: String,
+    #[skipper(skip_new)]
+    pub created_at: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+fn main() {
+    let new_model = NewModel {
+        name: "Pancake".to_owned(),
+    };
+    // There's no value for `created_at` until the database provides one.
+    let _model = new_model.into_model(1);
+}
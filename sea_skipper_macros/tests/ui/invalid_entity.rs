@@ -0,0 +1,9 @@
+use sea_skipper_macros::DeriveModelCondition;
+
+#[derive(DeriveModelCondition)]
+#[sea_orm(entity = "super::cake::")]
+pub struct Filter {
+    pub name: String,
+}
+
+fn main() {}
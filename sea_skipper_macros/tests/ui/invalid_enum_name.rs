@@ -0,0 +1,11 @@
+use sea_skipper_macros::DeriveNewModel;
+
+#[derive(DeriveNewModel)]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    #[sea_orm(enum_name = "not an ident")]
+    pub name: String,
+}
+
+fn main() {}
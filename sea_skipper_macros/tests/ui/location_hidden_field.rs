@@ -0,0 +1,11 @@
+use sea_skipper_macros::DeriveViewModel;
+
+#[derive(DeriveViewModel)]
+#[skipper(location = "/users/{email}")]
+pub struct Model {
+    pub id: i32,
+    #[skipper(hidden)]
+    pub email: String,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use sea_skipper_macros::DeriveNewModel;
+
+#[derive(DeriveNewModel)]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    #[skipper(default, read_only)]
+    pub created_at: i64,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use sea_skipper_macros::DerivePatchModel;
+
+#[derive(DerivePatchModel)]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    #[skipper(read_only, write_only)]
+    pub token: String,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use sea_skipper_macros::DeriveResource;
+
+#[derive(DeriveResource)]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    #[sea_orm(unique)]
+    #[skipper(alternate_key)]
+    pub code: i64,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use sea_skipper_macros::DeriveResource;
+
+#[derive(DeriveResource)]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    #[skipper(alternate_key)]
+    pub slug: String,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use sea_skipper_macros::DeriveResource;
+
+#[derive(DeriveResource)]
+pub struct Model(i32);
+
+fn main() {}
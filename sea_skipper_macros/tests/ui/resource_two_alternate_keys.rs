@@ -0,0 +1,15 @@
+use sea_skipper_macros::DeriveResource;
+
+#[derive(DeriveResource)]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    #[sea_orm(unique)]
+    #[skipper(alternate_key)]
+    pub slug: String,
+    #[sea_orm(unique)]
+    #[skipper(alternate_key)]
+    pub name: String,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use sea_skipper_macros::DeriveResource;
+
+#[derive(DeriveResource)]
+#[skipper(resource(path = "/cakes/{slug}"))]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub name: String,
+}
+
+fn main() {}
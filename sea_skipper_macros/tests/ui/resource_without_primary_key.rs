@@ -0,0 +1,9 @@
+use sea_skipper_macros::DeriveResource;
+
+#[derive(DeriveResource)]
+pub struct Model {
+    pub id: i32,
+    pub name: String,
+}
+
+fn main() {}
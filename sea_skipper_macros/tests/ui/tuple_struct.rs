@@ -0,0 +1,6 @@
+use sea_skipper_macros::DeriveNewModel;
+
+#[derive(DeriveNewModel)]
+pub struct Model(i32, String);
+
+fn main() {}
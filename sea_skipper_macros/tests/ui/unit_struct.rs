@@ -0,0 +1,6 @@
+use sea_skipper_macros::DeriveModelCondition;
+
+#[derive(DeriveModelCondition)]
+pub struct Filter;
+
+fn main() {}
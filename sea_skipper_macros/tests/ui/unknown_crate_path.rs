@@ -0,0 +1,17 @@
+use sea_orm::entity::prelude::*;
+use sea_skipper_macros::DeriveModelCondition;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveModelCondition)]
+#[sea_orm(table_name = "cake")]
+#[skipper(crate = "not_sea_skipper")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use sea_skipper_macros::DerivePatchModel;
+
+#[derive(DerivePatchModel)]
+#[skipper(patch_model(nmae = "CakePatch"))]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub name: String,
+}
+
+fn main() {}
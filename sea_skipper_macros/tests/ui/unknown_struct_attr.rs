@@ -0,0 +1,11 @@
+use sea_skipper_macros::DeriveNewModel;
+
+#[derive(DeriveNewModel)]
+#[skipper(new_modle(name = "CreateCake"))]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub name: String,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use sea_skipper_macros::DeriveNewModel;
+
+#[derive(DeriveNewModel)]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    #[skipper(validate(regex = "(\\w{1000}){1000}"))]
+    pub name: String,
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+/// The state of an optional field in a `NewModel` builder (derived with [`DeriveNewModel`]).
+///
+/// Builders track each field's state in their type: `()` for a field that was not set and `(T,)`
+/// for a field set to a `T`. Required fields must be `(T,)` to call `build()`, while optional
+/// fields (`Option` fields and fields with `#[skipper(default)]`) may be in either state.
+pub trait BuilderField<T> {
+    /// Returns the value of the field, or `default()` if the field was not set.
+    fn value_or_else(self, default: impl FnOnce() -> T) -> T;
+}
+
+impl<T> BuilderField<T> for () {
+    fn value_or_else(self, default: impl FnOnce() -> T) -> T {
+        default()
+    }
+}
+
+impl<T> BuilderField<T> for (T,) {
+    fn value_or_else(self, _default: impl FnOnce() -> T) -> T {
+        self.0
+    }
+}
@@ -0,0 +1,491 @@
+use sea_orm::{ConnAcquireErr, DbErr, SqlErr};
+
+/// A database-independent classification of a [`DbErr`].
+///
+/// Construct with [`DbErrorKind::from_db_err()`].
+///
+/// Errors raised by the database are classified by inspecting the `sqlx` error of the backend
+/// enabled with `sea_skipper`'s `sqlx-mysql`, `sqlx-postgres` or `sqlx-sqlite` features:
+/// - Postgres: SQLSTATE codes (e.g. `23505` for unique violations).
+/// - MySQL: server error numbers (e.g. `1062` for duplicate entries).
+/// - SQLite: extended result codes (e.g. `2067` for `SQLITE_CONSTRAINT_UNIQUE`).
+///
+/// Without these features, unique and foreign key violations are still classified (without a
+/// constraint name) using [`DbErr::sql_err()`] when one of `sea-orm`'s `sqlx-*` features is enabled.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DbErrorKind {
+    /// A unique (or primary key) constraint was violated.
+    UniqueViolation {
+        /// Name of the violated constraint (or index) when reported by the database.
+        constraint: Option<String>,
+    },
+    /// A foreign key constraint was violated.
+    ForeignKeyViolation {
+        /// Name of the violated constraint when reported by the database.
+        constraint: Option<String>,
+    },
+    /// A `NOT NULL` constraint was violated.
+    NotNullViolation {
+        /// Name of the column that must not be null when reported by the database.
+        column: Option<String>,
+    },
+    /// A `CHECK` constraint was violated.
+    CheckViolation {
+        /// Name of the violated constraint when reported by the database.
+        constraint: Option<String>,
+    },
+    /// The transaction was aborted because of a deadlock (or a serialization failure) and may be retried.
+    Deadlock,
+    /// The statement was cancelled, timed out, or could not acquire a lock (e.g. SQLite's
+    /// `SQLITE_BUSY` & `SQLITE_LOCKED`) or a connection.
+    Timeout,
+    /// The connection to the database failed or was closed.
+    Connection,
+    /// Any other error.
+    Other,
+}
+
+impl DbErrorKind {
+    /// Classifies `err` into a [`DbErrorKind`].
+    #[must_use]
+    pub fn from_db_err(err: &DbErr) -> Self {
+        match err {
+            DbErr::ConnectionAcquire(ConnAcquireErr::Timeout) => return Self::Timeout,
+            DbErr::ConnectionAcquire(ConnAcquireErr::ConnectionClosed) => return Self::Connection,
+            _ => {}
+        }
+
+        #[cfg(any(
+            feature = "sqlx-mysql",
+            feature = "sqlx-postgres",
+            feature = "sqlx-sqlite"
+        ))]
+        if let Some(kind) = sqlx_backend::classify(err) {
+            return kind;
+        }
+
+        match err.sql_err() {
+            Some(SqlErr::UniqueConstraintViolation(_)) => {
+                Self::UniqueViolation { constraint: None }
+            }
+            Some(SqlErr::ForeignKeyConstraintViolation(_)) => {
+                Self::ForeignKeyViolation { constraint: None }
+            }
+            _ if matches!(err, DbErr::Conn(_)) => Self::Connection,
+            _ => Self::Other,
+        }
+    }
+
+    /// Returns the name of the violated constraint, if any.
+    #[must_use]
+    pub fn constraint(&self) -> Option<&str> {
+        match self {
+            Self::UniqueViolation { constraint }
+            | Self::ForeignKeyViolation { constraint }
+            | Self::CheckViolation { constraint } => constraint.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Returns true if this is a unique (or primary key) constraint violation.
+    #[must_use]
+    pub fn is_unique_violation(&self) -> bool {
+        matches!(self, Self::UniqueViolation { .. })
+    }
+
+    /// Returns true if retrying the operation may succeed (i.e. [`Self::Deadlock`] or [`Self::Timeout`]).
+    #[must_use]
+    pub fn is_transient(&self) -> bool {
+        matches!(self, Self::Deadlock | Self::Timeout)
+    }
+}
+
+impl From<&DbErr> for DbErrorKind {
+    fn from(err: &DbErr) -> Self {
+        Self::from_db_err(err)
+    }
+}
+
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-postgres",
+    feature = "sqlx-sqlite"
+))]
+mod sqlx_backend {
+    use sea_orm::{DbErr, RuntimeErr};
+    use sqlx::error::DatabaseError;
+
+    use super::DbErrorKind;
+
+    /// Classifies `sqlx` errors wrapped by `err` (returns `None` for other errors).
+    pub(super) fn classify(err: &DbErr) -> Option<DbErrorKind> {
+        let sqlx_err = match err {
+            DbErr::Conn(RuntimeErr::SqlxError(e))
+            | DbErr::Exec(RuntimeErr::SqlxError(e))
+            | DbErr::Query(RuntimeErr::SqlxError(e)) => e,
+            _ => return None,
+        };
+
+        match sqlx_err {
+            sqlx::Error::Database(e) => Some(classify_database_error(e.as_ref())),
+            sqlx::Error::PoolTimedOut => Some(DbErrorKind::Timeout),
+            sqlx::Error::Io(_)
+            | sqlx::Error::Tls(_)
+            | sqlx::Error::PoolClosed
+            | sqlx::Error::WorkerCrashed => Some(DbErrorKind::Connection),
+            _ => None,
+        }
+    }
+
+    fn classify_database_error(e: &dyn DatabaseError) -> DbErrorKind {
+        #[cfg(feature = "sqlx-postgres")]
+        if let Some(e) = e.try_downcast_ref::<sqlx::postgres::PgDatabaseError>() {
+            return postgres(e);
+        }
+        #[cfg(feature = "sqlx-mysql")]
+        if let Some(e) = e.try_downcast_ref::<sqlx::mysql::MySqlDatabaseError>() {
+            return mysql(e);
+        }
+        #[cfg(feature = "sqlx-sqlite")]
+        if e.try_downcast_ref::<sqlx::sqlite::SqliteError>().is_some() {
+            return sqlite(e);
+        }
+        DbErrorKind::Other
+    }
+
+    #[cfg(feature = "sqlx-postgres")]
+    fn postgres(e: &sqlx::postgres::PgDatabaseError) -> DbErrorKind {
+        postgres_code(e.code(), e.constraint(), e.column())
+    }
+
+    /// Classifies Postgres errors by SQLSTATE `code`.
+    #[cfg(feature = "sqlx-postgres")]
+    fn postgres_code(code: &str, constraint: Option<&str>, column: Option<&str>) -> DbErrorKind {
+        let constraint = constraint.map(ToString::to_string);
+        match code {
+            "23505" => DbErrorKind::UniqueViolation { constraint },
+            "23503" => DbErrorKind::ForeignKeyViolation { constraint },
+            "23502" => DbErrorKind::NotNullViolation {
+                column: column.map(ToString::to_string),
+            },
+            "23514" => DbErrorKind::CheckViolation { constraint },
+            // deadlock_detected, serialization_failure
+            "40P01" | "40001" => DbErrorKind::Deadlock,
+            // query_canceled (e.g. statement_timeout), lock_not_available (e.g. lock_timeout)
+            "57014" | "55P03" => DbErrorKind::Timeout,
+            // admin_shutdown, crash_shutdown, cannot_connect_now & connection exceptions (08xxx)
+            "57P01" | "57P02" | "57P03" => DbErrorKind::Connection,
+            code if code.starts_with("08") => DbErrorKind::Connection,
+            _ => DbErrorKind::Other,
+        }
+    }
+
+    #[cfg(feature = "sqlx-mysql")]
+    fn mysql(e: &sqlx::mysql::MySqlDatabaseError) -> DbErrorKind {
+        mysql_code(e.number(), e.message())
+    }
+
+    /// Classifies MySQL errors by server error `number`, parsing names from the `message`.
+    #[cfg(feature = "sqlx-mysql")]
+    fn mysql_code(number: u16, message: &str) -> DbErrorKind {
+        match number {
+            // ER_DUP_KEY, ER_DUP_ENTRY, ER_DUP_UNIQUE, ER_DUP_ENTRY_WITH_KEY_NAME
+            1022 | 1062 | 1169 | 1586 => DbErrorKind::UniqueViolation {
+                constraint: quoted_after(message, "for key "),
+            },
+            // ER_NO_REFERENCED_ROW(_2), ER_ROW_IS_REFERENCED(_2)
+            1216 | 1217 | 1451 | 1452 => DbErrorKind::ForeignKeyViolation {
+                constraint: quoted_after(message, "CONSTRAINT "),
+            },
+            // ER_BAD_NULL_ERROR, ER_NO_DEFAULT_FOR_FIELD
+            1048 | 1364 => DbErrorKind::NotNullViolation {
+                column: quoted_after(message, "Column ")
+                    .or_else(|| quoted_after(message, "Field ")),
+            },
+            // ER_CHECK_CONSTRAINT_VIOLATED
+            3819 => DbErrorKind::CheckViolation {
+                constraint: quoted_after(message, "constraint "),
+            },
+            // ER_LOCK_DEADLOCK
+            1213 => DbErrorKind::Deadlock,
+            // ER_LOCK_WAIT_TIMEOUT, ER_QUERY_TIMEOUT, ER_QUERY_INTERRUPTED
+            1205 | 3024 | 1317 => DbErrorKind::Timeout,
+            // ER_CON_COUNT_ERROR, ER_SERVER_SHUTDOWN
+            1040 | 1053 => DbErrorKind::Connection,
+            _ => DbErrorKind::Other,
+        }
+    }
+
+    #[cfg(feature = "sqlx-sqlite")]
+    fn sqlite(e: &dyn DatabaseError) -> DbErrorKind {
+        let code: i32 = e
+            .code()
+            .and_then(|code| code.parse().ok())
+            .unwrap_or_default();
+        sqlite_code(code, e.message())
+    }
+
+    /// Classifies SQLite errors by extended result `code`, parsing names from the `message`.
+    #[cfg(feature = "sqlx-sqlite")]
+    fn sqlite_code(code: i32, message: &str) -> DbErrorKind {
+        // SQLite reports the failed constraint after a colon, e.g. `UNIQUE constraint failed: cake.name`.
+        let detail = message
+            .split_once(": ")
+            .map(|(_, detail)| detail.to_string());
+        match code {
+            // SQLITE_CONSTRAINT_UNIQUE, SQLITE_CONSTRAINT_PRIMARYKEY
+            2067 | 1555 => DbErrorKind::UniqueViolation { constraint: None },
+            // SQLITE_CONSTRAINT_FOREIGNKEY
+            787 => DbErrorKind::ForeignKeyViolation { constraint: None },
+            // SQLITE_CONSTRAINT_NOTNULL
+            1299 => DbErrorKind::NotNullViolation {
+                column: detail.map(|column| match column.split_once('.') {
+                    Some((_table, column)) => column.to_string(),
+                    None => column,
+                }),
+            },
+            // SQLITE_CONSTRAINT_CHECK
+            275 => DbErrorKind::CheckViolation { constraint: detail },
+            // SQLITE_BUSY & SQLITE_LOCKED (& extended codes), SQLITE_INTERRUPT
+            5 | 261 | 517 | 773 | 6 | 262 | 518 | 9 => DbErrorKind::Timeout,
+            _ => DbErrorKind::Other,
+        }
+    }
+
+    /// Returns the first quoted (with `'` or `` ` ``) name following `prefix` in `message`.
+    #[cfg(feature = "sqlx-mysql")]
+    fn quoted_after(message: &str, prefix: &str) -> Option<String> {
+        let rest = &message[message.find(prefix)? + prefix.len()..];
+        let quote = rest.chars().next().filter(|c| *c == '\'' || *c == '`')?;
+        let rest = &rest[1..];
+        let name = &rest[..rest.find(quote)?];
+        // MySQL 8 reports unique keys qualified by table name (e.g. `cake.name`).
+        Some(name.rsplit('.').next().unwrap_or(name).to_string())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use sea_orm::{DbErr, RuntimeErr};
+
+        use super::*;
+
+        #[test]
+        fn classify_sqlx_errors() {
+            let exec = |e| DbErr::Exec(RuntimeErr::SqlxError(e));
+            assert_eq!(
+                classify(&exec(sqlx::Error::PoolTimedOut)),
+                Some(DbErrorKind::Timeout)
+            );
+            assert_eq!(
+                classify(&exec(sqlx::Error::PoolClosed)),
+                Some(DbErrorKind::Connection)
+            );
+            assert_eq!(classify(&exec(sqlx::Error::RowNotFound)), None);
+            assert_eq!(classify(&DbErr::Custom("custom".to_string())), None);
+        }
+
+        #[cfg(feature = "sqlx-postgres")]
+        #[test]
+        fn postgres_sqlstate_codes() {
+            assert_eq!(
+                postgres_code("23505", Some("idx-cake-name"), None),
+                DbErrorKind::UniqueViolation {
+                    constraint: Some("idx-cake-name".to_string())
+                }
+            );
+            assert_eq!(
+                postgres_code("23503", Some("fk-slice-cake_id"), None),
+                DbErrorKind::ForeignKeyViolation {
+                    constraint: Some("fk-slice-cake_id".to_string())
+                }
+            );
+            assert_eq!(
+                postgres_code("23502", None, Some("name")),
+                DbErrorKind::NotNullViolation {
+                    column: Some("name".to_string())
+                }
+            );
+            assert_eq!(
+                postgres_code("23514", Some("cake_price_check"), None),
+                DbErrorKind::CheckViolation {
+                    constraint: Some("cake_price_check".to_string())
+                }
+            );
+            assert_eq!(postgres_code("40P01", None, None), DbErrorKind::Deadlock);
+            assert_eq!(postgres_code("40001", None, None), DbErrorKind::Deadlock);
+            assert_eq!(postgres_code("57014", None, None), DbErrorKind::Timeout);
+            assert_eq!(postgres_code("55P03", None, None), DbErrorKind::Timeout);
+            assert_eq!(postgres_code("57P01", None, None), DbErrorKind::Connection);
+            assert_eq!(postgres_code("08006", None, None), DbErrorKind::Connection);
+            assert_eq!(postgres_code("42P01", None, None), DbErrorKind::Other);
+        }
+
+        #[cfg(feature = "sqlx-mysql")]
+        #[test]
+        fn mysql_error_numbers() {
+            // MySQL 8 qualifies the key with the table name, MySQL 5.7 doesn't.
+            for message in [
+                "Duplicate entry 'Pancake' for key 'cake.idx-cake-name'",
+                "Duplicate entry 'Pancake' for key 'idx-cake-name'",
+            ] {
+                assert_eq!(
+                    mysql_code(1062, message),
+                    DbErrorKind::UniqueViolation {
+                        constraint: Some("idx-cake-name".to_string())
+                    }
+                );
+            }
+            assert_eq!(
+                mysql_code(
+                    1452,
+                    "Cannot add or update a child row: a foreign key constraint fails \
+                     (`shop`.`slice`, CONSTRAINT `fk-slice-cake_id` FOREIGN KEY (`cake_id`) \
+                     REFERENCES `cake` (`id`))"
+                ),
+                DbErrorKind::ForeignKeyViolation {
+                    constraint: Some("fk-slice-cake_id".to_string())
+                }
+            );
+            assert_eq!(
+                mysql_code(1048, "Column 'name' cannot be null"),
+                DbErrorKind::NotNullViolation {
+                    column: Some("name".to_string())
+                }
+            );
+            assert_eq!(
+                mysql_code(1364, "Field 'name' doesn't have a default value"),
+                DbErrorKind::NotNullViolation {
+                    column: Some("name".to_string())
+                }
+            );
+            assert_eq!(
+                mysql_code(3819, "Check constraint 'cake_chk_1' is violated."),
+                DbErrorKind::CheckViolation {
+                    constraint: Some("cake_chk_1".to_string())
+                }
+            );
+            assert_eq!(
+                mysql_code(
+                    1213,
+                    "Deadlock found when trying to get lock; try restarting transaction"
+                ),
+                DbErrorKind::Deadlock
+            );
+            assert_eq!(
+                mysql_code(
+                    1205,
+                    "Lock wait timeout exceeded; try restarting transaction"
+                ),
+                DbErrorKind::Timeout
+            );
+            assert_eq!(
+                mysql_code(1040, "Too many connections"),
+                DbErrorKind::Connection
+            );
+            assert_eq!(
+                mysql_code(1146, "Table 'shop.pie' doesn't exist"),
+                DbErrorKind::Other
+            );
+        }
+
+        #[cfg(feature = "sqlx-mysql")]
+        #[test]
+        fn mysql_quoted_after() {
+            assert_eq!(
+                quoted_after("for key `name`", "for key ").as_deref(),
+                Some("name")
+            );
+            // Missing prefix, unquoted or unterminated names.
+            assert_eq!(quoted_after("Duplicate entry 'a'", "for key "), None);
+            assert_eq!(quoted_after("for key name", "for key "), None);
+            assert_eq!(quoted_after("for key 'name", "for key "), None);
+        }
+
+        #[cfg(feature = "sqlx-sqlite")]
+        #[test]
+        fn sqlite_extended_result_codes() {
+            assert_eq!(
+                sqlite_code(2067, "UNIQUE constraint failed: cake.name"),
+                DbErrorKind::UniqueViolation { constraint: None }
+            );
+            assert_eq!(
+                sqlite_code(1555, "UNIQUE constraint failed: cake.id"),
+                DbErrorKind::UniqueViolation { constraint: None }
+            );
+            assert_eq!(
+                sqlite_code(787, "FOREIGN KEY constraint failed"),
+                DbErrorKind::ForeignKeyViolation { constraint: None }
+            );
+            assert_eq!(
+                sqlite_code(1299, "NOT NULL constraint failed: cake.name"),
+                DbErrorKind::NotNullViolation {
+                    column: Some("name".to_string())
+                }
+            );
+            assert_eq!(
+                sqlite_code(275, "CHECK constraint failed: price_positive"),
+                DbErrorKind::CheckViolation {
+                    constraint: Some("price_positive".to_string())
+                }
+            );
+            assert_eq!(sqlite_code(5, "database is locked"), DbErrorKind::Timeout);
+            assert_eq!(
+                sqlite_code(6, "database table is locked: cake"),
+                DbErrorKind::Timeout
+            );
+            assert_eq!(
+                sqlite_code(262, "database table is locked"),
+                DbErrorKind::Timeout
+            );
+            assert_eq!(sqlite_code(9, "interrupted"), DbErrorKind::Timeout);
+            assert_eq!(sqlite_code(1, "no such table: pie"), DbErrorKind::Other);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{ConnAcquireErr, DbErr, RuntimeErr};
+
+    use super::DbErrorKind;
+
+    #[test]
+    fn from_db_err_without_database_error() {
+        assert_eq!(
+            DbErrorKind::from_db_err(&DbErr::ConnectionAcquire(ConnAcquireErr::Timeout)),
+            DbErrorKind::Timeout
+        );
+        assert_eq!(
+            DbErrorKind::from_db_err(&DbErr::ConnectionAcquire(ConnAcquireErr::ConnectionClosed)),
+            DbErrorKind::Connection
+        );
+        assert_eq!(
+            DbErrorKind::from_db_err(&DbErr::Conn(RuntimeErr::Internal("closed".to_string()))),
+            DbErrorKind::Connection
+        );
+        assert_eq!(
+            DbErrorKind::from_db_err(&DbErr::RecordNotFound("cake".to_string())),
+            DbErrorKind::Other
+        );
+    }
+
+    #[test]
+    fn kind_helpers() {
+        let unique = DbErrorKind::UniqueViolation {
+            constraint: Some("idx-cake-name".to_string()),
+        };
+        assert!(unique.is_unique_violation());
+        assert_eq!(unique.constraint(), Some("idx-cake-name"));
+        assert!(!unique.is_transient());
+        assert!(DbErrorKind::Deadlock.is_transient());
+        assert!(DbErrorKind::Timeout.is_transient());
+        assert_eq!(
+            DbErrorKind::NotNullViolation {
+                column: Some("name".to_string())
+            }
+            .constraint(),
+            None
+        );
+    }
+}
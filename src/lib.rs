@@ -8,12 +8,43 @@ pub use location::*;
 /// Traits for converting URL query strings into conditions for filtering database queries.
 pub mod query_filter;
 
-/// [`Resource`] and [`DataTrait`] encapsulating `sea-orm` types and trait bounds for simpler generics.
+/// Parses URL query strings into filter, sort, field selection and pagination options for listing resources.
+pub mod list_query;
+
+/// [`Validate`](crate::validation::Validate) trait & validators for data models.
+pub mod validation;
+
+/// [`Resource`], [`ResourceId`] and [`DataTrait`] encapsulating `sea-orm` types and trait bounds for simpler generics.
 mod resource;
 pub use resource::*;
 
+/// [`RouteTemplate`] building routes & reverse-generating URLs of [`Resource`]s.
+mod route;
+pub use route::*;
+
+/// [`DbErrorKind`] classifying database errors (e.g. unique violations) across database backends.
+mod db_error;
+pub use db_error::*;
+
+/// [`BuilderField`] tracking the state of optional fields in `NewModel` builders.
+mod builder;
+pub use builder::*;
+
+/// [`PatchValue`] distinguishing absent fields from `null` fields in partial updates.
+mod patch;
+pub use patch::*;
+
+/// [`PatchDocument`] applying JSON Merge Patch & JSON Patch documents to [`Resource`] models.
+#[cfg(feature = "json-patch")]
+mod patch_document;
+#[cfg(feature = "json-patch")]
+pub use patch_document::*;
+
 mod orm;
 pub use orm::*;
 
 #[cfg(feature = "derive")]
 pub use sea_skipper_macros::*;
+
+#[cfg(test)]
+mod test_entity;
@@ -0,0 +1,227 @@
+//! The `list_query` module parses URL query strings for requests listing a collection of resources.
+//!
+//! A [`ListQuery`] combines a [`QueryFilter`] with sorting, field selection and pagination options
+//! so it can be applied to a `sea-orm` select query in one call with [`SelectExt`](crate::SelectExt).
+//!
+//! The following query string parameters are reserved (i.e. never used as filters):
+//! - `sort`: comma-separated list of sort keys; a key prefixed with `-` sorts in descending order
+//!   (e.g. `?sort=-price,name`).
+//! - `fields`: comma-separated list of fields to select (e.g. `?fields=id,name`).
+//! - `page`: the page number to fetch, starting at `1`.
+//! - `per_page`: the number of items per page (capped at [`MAX_PER_PAGE`]).
+//!
+//! Sort keys and fields are mapped to columns with [`QueryParams::column()`]; unknown keys are ignored.
+use std::collections::HashMap;
+
+use sea_orm::{EntityTrait, Order};
+
+use crate::query_filter::{QueryFilter, QueryParams};
+
+/// Query string parameter holding sort keys.
+pub const SORT_PARAM: &str = "sort";
+/// Query string parameter holding selected fields.
+pub const FIELDS_PARAM: &str = "fields";
+/// Query string parameter holding the page number.
+pub const PAGE_PARAM: &str = "page";
+/// Query string parameter holding the page size.
+pub const PER_PAGE_PARAM: &str = "per_page";
+
+/// Number of items per page when `per_page` is not in the query string.
+pub const DEFAULT_PER_PAGE: u64 = 20;
+/// Largest number of items per page that may be requested with `per_page`.
+pub const MAX_PER_PAGE: u64 = 100;
+
+/// Filter, sort, field selection and pagination options constructed from a request URL's query string.
+#[derive(Debug)]
+pub struct ListQuery<P: QueryParams> {
+    filter: QueryFilter<P>,
+    sort: Vec<(<P::Entity as EntityTrait>::Column, Order)>,
+    fields: Vec<<P::Entity as EntityTrait>::Column>,
+    page: u64,
+    per_page: u64,
+}
+
+impl<P: QueryParams> ListQuery<P> {
+    /// Constructs [`ListQuery`] from a request URL's `query_string`.
+    ///
+    /// Reserved parameters (`sort`, `fields`, `page`, `per_page`) configure sorting, field selection
+    /// and pagination; the remaining parameters are used to construct a [`QueryFilter`].
+    ///
+    /// Invalid `page` or `per_page` values fall back to the first page and [`DEFAULT_PER_PAGE`].
+    #[must_use]
+    pub fn from_query_string(mut query_string: HashMap<String, String>) -> Self {
+        let sort = query_string
+            .remove(SORT_PARAM)
+            .map(|sort| parse_sort::<P>(&sort))
+            .unwrap_or_default();
+        let fields = query_string
+            .remove(FIELDS_PARAM)
+            .map(|fields| parse_fields::<P>(&fields))
+            .unwrap_or_default();
+        let page = query_string
+            .remove(PAGE_PARAM)
+            .and_then(|page| page.parse::<u64>().ok())
+            .filter(|page| *page > 0)
+            .unwrap_or(1);
+        let per_page = query_string
+            .remove(PER_PAGE_PARAM)
+            .and_then(|per_page| per_page.parse::<u64>().ok())
+            .filter(|per_page| *per_page > 0)
+            .map_or(DEFAULT_PER_PAGE, |per_page| per_page.min(MAX_PER_PAGE));
+
+        Self {
+            filter: QueryFilter::from_query_string(query_string),
+            sort,
+            fields,
+            page,
+            per_page,
+        }
+    }
+
+    /// Returns the [`QueryFilter`] constructed from non-reserved query string parameters.
+    #[must_use]
+    pub fn filter(&self) -> &QueryFilter<P> {
+        &self.filter
+    }
+
+    /// Returns the columns to sort by (in order of precedence) with their sort [`Order`].
+    #[must_use]
+    pub fn sort(&self) -> &[(<P::Entity as EntityTrait>::Column, Order)] {
+        &self.sort
+    }
+
+    /// Returns the columns to select (empty when all columns should be selected).
+    #[must_use]
+    pub fn fields(&self) -> &[<P::Entity as EntityTrait>::Column] {
+        &self.fields
+    }
+
+    /// Returns the requested page number (starting at `1`).
+    #[must_use]
+    pub fn page(&self) -> u64 {
+        self.page
+    }
+
+    /// Returns the requested number of items per page.
+    #[must_use]
+    pub fn per_page(&self) -> u64 {
+        self.per_page
+    }
+
+    /// Returns the number of items to skip to reach the requested page.
+    #[must_use]
+    pub fn offset(&self) -> u64 {
+        (self.page - 1).saturating_mul(self.per_page)
+    }
+}
+
+/// Parses a comma-separated list of sort keys into columns and their sort [`Order`].
+fn parse_sort<P: QueryParams>(sort: &str) -> Vec<(<P::Entity as EntityTrait>::Column, Order)> {
+    sort.split(',')
+        .map(str::trim)
+        .filter_map(|key| match key.strip_prefix('-') {
+            Some(key) => P::column(key).map(|column| (column, Order::Desc)),
+            None => P::column(key.trim_start_matches('+')).map(|column| (column, Order::Asc)),
+        })
+        .collect()
+}
+
+/// Parses a comma-separated list of fields into columns.
+fn parse_fields<P: QueryParams>(fields: &str) -> Vec<<P::Entity as EntityTrait>::Column> {
+    fields
+        .split(',')
+        .map(str::trim)
+        .filter_map(P::column)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{DbBackend, QueryFilter as _, QueryTrait};
+
+    use super::*;
+    use crate::test_entity::cake::{self, CakeParams};
+
+    fn list_query(query_string: &[(&str, &str)]) -> ListQuery<CakeParams> {
+        ListQuery::from_query_string(
+            query_string
+                .iter()
+                .map(|(param, value)| (param.to_string(), value.to_string()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn defaults() {
+        let query = list_query(&[]);
+        assert!(query.sort().is_empty());
+        assert!(query.fields().is_empty());
+        assert_eq!(query.page(), 1);
+        assert_eq!(query.per_page(), DEFAULT_PER_PAGE);
+        assert_eq!(query.offset(), 0);
+    }
+
+    #[test]
+    fn sort() {
+        let query = list_query(&[("sort", "-created_at, +name,unknown,id")]);
+        assert_eq!(
+            format!("{:?}", query.sort()),
+            "[(CreatedAt, Desc), (Name, Asc), (Id, Asc)]"
+        );
+    }
+
+    #[test]
+    fn fields() {
+        let query = list_query(&[("fields", "id, name,secret,")]);
+        assert_eq!(format!("{:?}", query.fields()), "[Id, Name]");
+    }
+
+    #[test]
+    fn pagination() {
+        let query = list_query(&[("page", "3"), ("per_page", "10")]);
+        assert_eq!(query.page(), 3);
+        assert_eq!(query.per_page(), 10);
+        assert_eq!(query.offset(), 20);
+    }
+
+    #[test]
+    fn per_page_is_clamped() {
+        assert_eq!(list_query(&[("per_page", "1000")]).per_page(), MAX_PER_PAGE);
+        assert_eq!(
+            list_query(&[("per_page", "0")]).per_page(),
+            DEFAULT_PER_PAGE
+        );
+        assert_eq!(
+            list_query(&[("per_page", "-5")]).per_page(),
+            DEFAULT_PER_PAGE
+        );
+        assert_eq!(
+            list_query(&[("per_page", "ten")]).per_page(),
+            DEFAULT_PER_PAGE
+        );
+    }
+
+    #[test]
+    fn invalid_page_is_first_page() {
+        for page in ["0", "-1", "first", ""] {
+            assert_eq!(list_query(&[("page", page)]).page(), 1, "{page}");
+        }
+    }
+
+    #[test]
+    fn reserved_params_are_not_filters() {
+        let query = list_query(&[
+            ("name", "Pancake"),
+            ("sort", "id"),
+            ("fields", "id"),
+            ("page", "2"),
+            ("per_page", "5"),
+            ("unknown", "x"),
+        ]);
+        let sql = cake::Entity::find()
+            .filter(query.filter().to_condition())
+            .build(DbBackend::Postgres)
+            .to_string();
+        assert!(sql.ends_with(r#"FROM "cake" WHERE "cake"."name" = 'Pancake'"#));
+    }
+}
@@ -1,4 +1,9 @@
 /// Trait providing the `Location` of an API resource.
+///
+/// Use [`ResourceId::to_path()`](crate::ResourceId::to_path) to render a (composite) primary key
+/// into the path of a `Location`, e.g. `format!("/links/{}", (self.tenant_id, self.slug.clone()).to_path())`,
+/// or render it with the [`RouteTemplate`](crate::RouteTemplate) of the resource. A
+/// [`BaseUrl`](crate::BaseUrl) makes the `Location` absolute.
 pub trait Location {
     fn location(&self) -> String;
 }
@@ -0,0 +1,91 @@
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, EntityTrait, Iterable, Value,
+};
+
+/// Trait for converting an `ActiveModel` into a [`Condition`] for database queries.
+///
+/// Unlike [`ModelCondition`](crate::ModelCondition), only columns whose value is present (i.e.
+/// [`ActiveValue::Set`] or [`ActiveValue::Unchanged`]) are compared; [`ActiveValue::NotSet`] columns
+/// are skipped.
+///
+/// Both conditions are `None` when no column is present, since an empty `ALL` condition would match
+/// every record (e.g. deleting or updating a whole table).
+///
+/// This is implemented for all types implementing [`ActiveModelTrait`].
+pub trait ActiveModelCondition: ActiveModelTrait {
+    /// Returns an `ALL` [`Condition`] that filters for column values equal to `Self`'s present values,
+    /// or `None` if no value is present.
+    fn all_condition(&self) -> Option<Condition>;
+
+    /// Returns an `ANY` [`Condition`] that filters for records with at least one column value equal
+    /// to one of `Self`'s present values, or `None` if no value is present.
+    fn any_condition(&self) -> Option<Condition>;
+}
+
+impl<A: ActiveModelTrait> ActiveModelCondition for A {
+    fn all_condition(&self) -> Option<Condition> {
+        present_condition(self, Condition::all())
+    }
+
+    fn any_condition(&self) -> Option<Condition> {
+        present_condition(self, Condition::any())
+    }
+}
+
+/// Adds a comparison of each present value of `active_model` to `condition` (`None` if there are
+/// none).
+fn present_condition<A: ActiveModelTrait>(
+    active_model: &A,
+    condition: Condition,
+) -> Option<Condition> {
+    let mut present = present_values(active_model).peekable();
+    present.peek()?;
+    Some(present.fold(condition, |condition, (column, value)| {
+        condition.add(column.eq(value))
+    }))
+}
+
+/// Returns the columns of `active_model` with a present value (`Set` or `Unchanged`) and their values.
+fn present_values<A: ActiveModelTrait>(
+    active_model: &A,
+) -> impl Iterator<Item = (<A::Entity as EntityTrait>::Column, Value)> + '_ {
+    <A::Entity as EntityTrait>::Column::iter().filter_map(|column| match active_model.get(column) {
+        ActiveValue::Set(value) | ActiveValue::Unchanged(value) => Some((column, value)),
+        ActiveValue::NotSet => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{ActiveValue::Set, DbBackend, QueryFilter, QueryTrait};
+
+    use super::*;
+    use crate::test_entity::cake;
+
+    fn sql(condition: Condition) -> String {
+        cake::Entity::find()
+            .filter(condition)
+            .build(DbBackend::Postgres)
+            .to_string()
+    }
+
+    #[test]
+    fn no_present_values() {
+        let active_model = <cake::ActiveModel as Default>::default();
+        assert!(active_model.all_condition().is_none());
+        assert!(active_model.any_condition().is_none());
+    }
+
+    #[test]
+    fn present_values_only() {
+        let active_model = cake::ActiveModel {
+            id: ActiveValue::Unchanged(1),
+            name: Set("Pancake".to_string()),
+            ..Default::default()
+        };
+        assert!(sql(active_model.all_condition().unwrap())
+            .ends_with(r#"WHERE "cake"."id" = 1 AND "cake"."name" = 'Pancake'"#));
+        assert!(sql(active_model.any_condition().unwrap())
+            .ends_with(r#"WHERE "cake"."id" = 1 OR "cake"."name" = 'Pancake'"#));
+    }
+}
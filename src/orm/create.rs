@@ -0,0 +1,62 @@
+use sea_orm::{ActiveModelTrait, Condition, ConnectionTrait, DbErr, QueryFilter};
+
+use crate::{DataTrait, DbErrorKind, ModelCondition, Resource};
+
+/// The outcome of [`create_idempotent()`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CreateOutcome<M> {
+    /// A new record was inserted.
+    Created(M),
+    /// The insert violated a unique constraint and an existing record matches all of the new data's
+    /// values, so the create request is considered idempotent (e.g. respond `303 See Other`).
+    AlreadyExists(M),
+    /// The insert violated a unique constraint and no existing record matches all of the new data's
+    /// values (e.g. respond `409 Conflict`).
+    ///
+    /// Contains an existing record conflicting with one of the new data's unique keys (if found).
+    Conflict(Option<M>),
+}
+
+/// Inserts `data` and looks up an equivalent existing record when the insert violates a unique
+/// constraint.
+///
+/// When the insert fails with a unique constraint violation, the existing record matching
+/// [`ModelCondition::all_condition()`] results in [`CreateOutcome::AlreadyExists`]. Otherwise, a
+/// record matching one of [`ModelCondition::unique_conditions()`] is returned in
+/// [`CreateOutcome::Conflict`].
+///
+/// Unique constraint violations are detected with [`DbErrorKind`].
+///
+/// On Postgres, a failed statement aborts the transaction it runs in, so when `db` is a
+/// transaction the lookup after a unique constraint violation fails (with `current transaction
+/// is aborted`) instead of finding the existing record, and the caller's transaction can only be
+/// rolled back. Call this with a connection that is not in a transaction on Postgres.
+///
+/// # Errors
+/// Returns a [`DbErr`] if the insert fails for any other reason than a unique constraint violation,
+/// or if looking up existing records fails.
+pub async fn create_idempotent<R, D, C>(db: &C, data: D) -> Result<CreateOutcome<R::Data>, DbErr>
+where
+    R: Resource,
+    D: DataTrait<R> + ModelCondition<Entity = R>,
+    C: ConnectionTrait,
+{
+    match data.clone().into_active_model().insert(db).await {
+        Ok(model) => Ok(CreateOutcome::Created(model)),
+        Err(err) if DbErrorKind::from_db_err(&err).is_unique_violation() => {
+            if let Some(model) = R::find().filter(data.all_condition()).one(db).await? {
+                return Ok(CreateOutcome::AlreadyExists(model));
+            }
+            let unique_conditions = data.unique_conditions();
+            if unique_conditions.is_empty() {
+                return Ok(CreateOutcome::Conflict(None));
+            }
+            let conflicting = unique_conditions
+                .into_iter()
+                .fold(Condition::any(), Condition::add);
+            let model = R::find().filter(conflicting).one(db).await?;
+            Ok(CreateOutcome::Conflict(model))
+        }
+        Err(err) => Err(err),
+    }
+}
@@ -1,2 +1,12 @@
+mod active_model_condition;
+pub use active_model_condition::*;
+mod create;
+pub use create::*;
 mod model_condition;
 pub use model_condition::*;
+mod new_model;
+pub use new_model::*;
+mod patch_model;
+pub use patch_model::*;
+mod select_ext;
+pub use select_ext::*;
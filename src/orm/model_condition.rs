@@ -1,8 +1,8 @@
-use sea_orm::{Condition, ModelTrait};
+use sea_orm::{Condition, EntityTrait};
 
 /// Trait for converting a data model into a [`Condition`] for database queries.
 ///
-/// [`to_all_condition()`] returns a `Condition` that selects database records whose values are
+/// [`all_condition()`] returns a `Condition` that selects database records whose values are
 /// equal to all of `Self`'s values.
 ///
 /// This is useful when working with `NewModel` structs (derived with [`DeriveNewModel`]) that
@@ -12,9 +12,117 @@ use sea_orm::{Condition, ModelTrait};
 /// Consider a use case where a `NewModel` contains a unique field (that is not part of the primary key)
 /// and a create (`POST`) request results in a unique constraint violation error. An HTTP API may want
 /// to determine whether a record matching `NewModel`'s values already exists in the database to
-/// decide between returning a `303 See Other` redirect versus a `409 Conflict` error.
-pub trait ModelCondition: ModelTrait {
+/// decide between returning a `303 See Other` redirect versus a `409 Conflict` error. Use
+/// [`unique_conditions()`] to find the record(s) conflicting with `NewModel`'s unique values, then
+/// compare them with `NewModel` to decide which response applies.
+///
+/// Only [`all_condition()`] is required: the other conditions default to it (and
+/// [`unique_conditions()`] to none), so manual implementations can override just the ones they
+/// need.
+///
+/// Each condition has a borrowing method (e.g. [`all_condition()`]) and a consuming `to_*` method
+/// (e.g. [`to_all_condition()`]). Fields with a `#[skipper(condition = false)]` attribute (e.g. an
+/// `updated_at` column) are left out of conditions implemented by [`DeriveModelCondition`].
+///
+/// [`all_condition()`]: Self::all_condition
+/// [`to_all_condition()`]: Self::to_all_condition
+/// [`unique_conditions()`]: Self::unique_conditions
+pub trait ModelCondition: Sized {
+    /// The entity whose columns the conditions filter on.
+    type Entity: EntityTrait;
+
     /// Returns an `ALL` [`Condition`] that filters for column values equal to `Self`'s values.
+    ///
+    /// With [`DeriveModelCondition`], `Option` fields whose value is `None` are compared with
+    /// `IS NULL`, so the condition only matches records with the same `NULL` columns (use
+    /// [`partial_condition()`](Self::partial_condition) to skip `None` fields instead).
     // TODO: Document an example of a model and the `Condition` this produces.
-    fn to_all_condition(self) -> Condition;
+    fn all_condition(&self) -> Condition;
+
+    /// Returns an `ANY` [`Condition`] that filters for records with at least one column value equal
+    /// to `Self`'s value.
+    ///
+    /// This is useful to check whether any existing record conflicts with one of `Self`'s values.
+    /// With [`DeriveModelCondition`], `Option` fields whose value is `None` are skipped. Defaults
+    /// to [`all_condition()`](Self::all_condition).
+    fn any_condition(&self) -> Condition {
+        self.all_condition()
+    }
+
+    /// Returns an `ALL` [`Condition`] like [`all_condition()`](Self::all_condition) that skips
+    /// `Option` fields whose value is `None`.
+    ///
+    /// This allows a partially-filled model (e.g. a `NewModel`) to be used as a query-by-example.
+    /// Defaults to [`all_condition()`](Self::all_condition).
+    fn partial_condition(&self) -> Condition {
+        self.all_condition()
+    }
+
+    /// Returns an `ALL` [`Condition`] for each unique key of `Self` that filters for records whose
+    /// unique key column values are equal to `Self`'s values.
+    ///
+    /// With [`DeriveModelCondition`], unique keys are declared with `#[sea_orm(unique)]` (a single
+    /// column key) and `#[skipper(unique_group = "...")]` (fields sharing a group name form a
+    /// composite key). The primary key is not included unless it is declared this way. Defaults to
+    /// no conditions.
+    fn unique_conditions(&self) -> Vec<Condition> {
+        Vec::new()
+    }
+
+    /// Consumes `self` and returns its [`all_condition()`](Self::all_condition).
+    fn to_all_condition(self) -> Condition {
+        self.all_condition()
+    }
+
+    /// Consumes `self` and returns its [`any_condition()`](Self::any_condition).
+    fn to_any_condition(self) -> Condition {
+        self.any_condition()
+    }
+
+    /// Consumes `self` and returns its [`partial_condition()`](Self::partial_condition).
+    fn to_partial_condition(self) -> Condition {
+        self.partial_condition()
+    }
+
+    /// Consumes `self` and returns its [`unique_conditions()`](Self::unique_conditions).
+    fn to_unique_conditions(self) -> Vec<Condition> {
+        self.unique_conditions()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{ColumnTrait, DbBackend, EntityTrait, QueryFilter, QueryTrait};
+
+    use super::*;
+    use crate::test_entity::cake;
+
+    /// Implements only the required method.
+    struct ByName(&'static str);
+
+    impl ModelCondition for ByName {
+        type Entity = cake::Entity;
+
+        fn all_condition(&self) -> Condition {
+            Condition::all().add(cake::Column::Name.eq(self.0))
+        }
+    }
+
+    fn sql(condition: Condition) -> String {
+        cake::Entity::find()
+            .filter(condition)
+            .build(DbBackend::Postgres)
+            .to_string()
+    }
+
+    #[test]
+    fn defaults_to_all_condition() {
+        let by_name = ByName("Pancake");
+        let all = sql(by_name.all_condition());
+        assert!(all.ends_with(r#"WHERE "cake"."name" = 'Pancake'"#));
+        assert_eq!(sql(by_name.any_condition()), all);
+        assert_eq!(sql(by_name.partial_condition()), all);
+        assert!(by_name.unique_conditions().is_empty());
+        assert_eq!(sql(by_name.to_all_condition()), all);
+    }
 }
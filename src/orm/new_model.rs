@@ -0,0 +1,70 @@
+use std::fmt;
+
+use sea_orm::{EntityTrait, IdenStatic, Value};
+
+/// Column `Column` of the entity `E`.
+type ColumnOf<E> = <E as EntityTrait>::Column;
+
+/// Trait for accessing the column values of a `NewModel` (derived with [`DeriveNewModel`]).
+///
+/// Unlike `ModelTrait`, a `NewModel` does not carry every column of its entity (e.g. primary key
+/// columns and fields with `#[skipper(skip_new)]` are left out), so accessing a column is fallible.
+/// [`columns()`](Self::columns) lists the columns the `NewModel` carries; [`try_get()`](Self::try_get)
+/// and [`try_set()`](Self::try_set) return a [`NewModelError`] for other columns instead of
+/// panicking.
+pub trait NewModelTrait {
+    /// The entity of the `Model` the `NewModel` is derived from.
+    type Entity: EntityTrait;
+
+    /// Returns the columns carried by the `NewModel` (in field order).
+    fn columns() -> &'static [ColumnOf<Self::Entity>];
+
+    /// Returns the value of `column`.
+    ///
+    /// # Errors
+    /// Returns [`NewModelError::MissingColumn`] if the `NewModel` does not carry `column`.
+    fn try_get(&self, column: ColumnOf<Self::Entity>) -> Result<Value, NewModelError>;
+
+    /// Sets the value of `column` to `value`.
+    ///
+    /// # Errors
+    /// Returns [`NewModelError::MissingColumn`] if the `NewModel` does not carry `column` and
+    /// [`NewModelError::TypeMismatch`] if `value` can't be converted to the field's type. The field
+    /// is left unchanged on error.
+    fn try_set(
+        &mut self,
+        column: ColumnOf<Self::Entity>,
+        value: Value,
+    ) -> Result<(), NewModelError>;
+
+    /// Returns true if the `NewModel` carries `column`.
+    #[must_use]
+    fn carries(column: ColumnOf<Self::Entity>) -> bool {
+        Self::columns()
+            .iter()
+            .any(|carried| carried.as_str() == column.as_str())
+    }
+}
+
+/// An error accessing a column of a `NewModel` with [`NewModelTrait`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NewModelError {
+    /// The named column is not carried by the `NewModel` (e.g. a primary key column).
+    MissingColumn(String),
+    /// The value set on the named column does not match the field's type.
+    TypeMismatch(String),
+}
+
+impl fmt::Display for NewModelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingColumn(column) => write!(f, "column `{column}` is not part of the model"),
+            Self::TypeMismatch(column) => {
+                write!(f, "value does not match the type of column `{column}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for NewModelError {}
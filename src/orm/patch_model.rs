@@ -0,0 +1,22 @@
+use sea_orm::EntityTrait;
+
+/// Trait describing which columns a `PatchModel` (derived with [`DerivePatchModel`]) can change.
+///
+/// Primary key columns and `#[skipper(read_only)]` columns are not part of a `PatchModel`.
+/// `PatchDocument::apply()` uses [`document_fields()`](Self::document_fields) to turn a patch
+/// document into a `PatchModel`, so patch documents can only change the `PatchModel`'s fields.
+pub trait PatchModelTrait {
+    /// The entity of the `Model` the `PatchModel` is derived from.
+    type Entity: EntityTrait;
+
+    /// Returns the columns with a `#[skipper(read_only)]` attribute (in field order).
+    fn read_only_columns() -> &'static [<Self::Entity as EntityTrait>::Column];
+
+    /// Returns the name of each field in patch documents paired with its serialized name in the
+    /// `PatchModel` (in field order).
+    ///
+    /// Patch documents are applied to the resource's `View`, so a field's name is its serialized
+    /// name in the `ViewModel` (e.g. after `#[skipper(view_rename = "...")]`). Hidden and
+    /// write-only fields, which aren't in the `ViewModel`, keep their `PatchModel` name.
+    fn document_fields() -> &'static [(&'static str, &'static str)];
+}
@@ -0,0 +1,297 @@
+use async_trait::async_trait;
+use sea_orm::{
+    ConnectionTrait, DbErr, EntityTrait, FromQueryResult, PaginatorTrait, QueryFilter, QueryOrder,
+    QuerySelect, Select, SelectTwo,
+};
+use serde::Serialize;
+
+use crate::list_query::ListQuery;
+use crate::query_filter::QueryParams;
+
+/// A page of items fetched with [`SelectExt::fetch_page()`] along with paging metadata.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Page<T> {
+    /// Items on this page.
+    pub items: Vec<T>,
+    /// This page's number (starting at `1`).
+    pub page: u64,
+    /// Maximum number of items on each page.
+    pub per_page: u64,
+    /// Total number of items matching the query (across all pages).
+    pub total_items: u64,
+    /// Total number of pages.
+    pub total_pages: u64,
+}
+
+/// Extension trait applying a [`ListQuery`] to `sea-orm` select queries.
+///
+/// This is implemented for [`Select<E>`] and [`SelectTwo<E, F>`] where the [`ListQuery`]'s
+/// [`QueryParams::Entity`] is `E`.
+#[async_trait]
+pub trait SelectExt: Sized {
+    /// The entity whose columns the [`ListQuery`] refers to.
+    type Entity: EntityTrait;
+
+    /// The type of items returned by [`Self::fetch_page()`].
+    type Item;
+
+    /// Applies the filter, sort, field selection and pagination (`LIMIT`/`OFFSET`) of `query`.
+    ///
+    /// Field selection replaces the selected columns with [`ListQuery::fields()`] (if any), so the
+    /// result should be read with a partial model (e.g. `into_model()` or `into_json()`). Field
+    /// selection is not applied to [`SelectTwo`] queries since it would drop the related entity's
+    /// columns.
+    #[must_use]
+    fn apply_query<P>(self, query: &ListQuery<P>) -> Self
+    where
+        P: QueryParams<Entity = Self::Entity>;
+
+    /// Fetches the page of items requested by `query` along with paging metadata.
+    ///
+    /// This applies the filter and sort of `query` and counts all matching items to determine the
+    /// total number of items and pages. Field selection is not applied because full models are
+    /// returned.
+    async fn fetch_page<P, C>(
+        self,
+        db: &C,
+        query: &ListQuery<P>,
+    ) -> Result<Page<Self::Item>, DbErr>
+    where
+        P: QueryParams<Entity = Self::Entity> + Sync,
+        C: ConnectionTrait;
+}
+
+#[async_trait]
+impl<E, M> SelectExt for Select<E>
+where
+    E: EntityTrait<Model = M>,
+    M: FromQueryResult + Sized + Send + Sync,
+{
+    type Entity = E;
+    type Item = M;
+
+    fn apply_query<P>(self, query: &ListQuery<P>) -> Self
+    where
+        P: QueryParams<Entity = E>,
+    {
+        let select = filter_and_sort(self, query);
+        let select = if query.fields().is_empty() {
+            select
+        } else {
+            select.select_only().columns(query.fields().iter().copied())
+        };
+        select.limit(query.per_page()).offset(query.offset())
+    }
+
+    async fn fetch_page<P, C>(self, db: &C, query: &ListQuery<P>) -> Result<Page<M>, DbErr>
+    where
+        P: QueryParams<Entity = E> + Sync,
+        C: ConnectionTrait,
+    {
+        let paginator = filter_and_sort(self, query).paginate(db, query.per_page());
+        let totals = paginator.num_items_and_pages().await?;
+        let items = paginator.fetch_page(query.page() - 1).await?;
+        Ok(Page {
+            items,
+            page: query.page(),
+            per_page: query.per_page(),
+            total_items: totals.number_of_items,
+            total_pages: totals.number_of_pages,
+        })
+    }
+}
+
+#[async_trait]
+impl<E, F, M, N> SelectExt for SelectTwo<E, F>
+where
+    E: EntityTrait<Model = M>,
+    F: EntityTrait<Model = N>,
+    M: FromQueryResult + Sized + Send + Sync,
+    N: FromQueryResult + Sized + Send + Sync,
+{
+    type Entity = E;
+    type Item = (M, Option<N>);
+
+    fn apply_query<P>(self, query: &ListQuery<P>) -> Self
+    where
+        P: QueryParams<Entity = E>,
+    {
+        filter_and_sort(self, query)
+            .limit(query.per_page())
+            .offset(query.offset())
+    }
+
+    async fn fetch_page<P, C>(
+        self,
+        db: &C,
+        query: &ListQuery<P>,
+    ) -> Result<Page<(M, Option<N>)>, DbErr>
+    where
+        P: QueryParams<Entity = E> + Sync,
+        C: ConnectionTrait,
+    {
+        let paginator = filter_and_sort(self, query).paginate(db, query.per_page());
+        let totals = paginator.num_items_and_pages().await?;
+        let items = paginator.fetch_page(query.page() - 1).await?;
+        Ok(Page {
+            items,
+            page: query.page(),
+            per_page: query.per_page(),
+            total_items: totals.number_of_items,
+            total_pages: totals.number_of_pages,
+        })
+    }
+}
+
+/// Applies the filter and sort of `query` to `select`.
+fn filter_and_sort<S, P>(select: S, query: &ListQuery<P>) -> S
+where
+    S: QueryFilter + QueryOrder,
+    P: QueryParams,
+{
+    query.sort().iter().fold(
+        select.filter(query.filter().to_condition()),
+        |select, (column, order)| select.order_by(*column, order.clone()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use sea_orm::{DbBackend, MockDatabase, QueryTrait, Transaction, Value};
+
+    use super::*;
+    use crate::list_query::MAX_PER_PAGE;
+    use crate::test_entity::cake::{self, CakeParams};
+
+    fn list_query(query_string: &[(&str, &str)]) -> ListQuery<CakeParams> {
+        ListQuery::from_query_string(
+            query_string
+                .iter()
+                .map(|(param, value)| (param.to_string(), value.to_string()))
+                .collect(),
+        )
+    }
+
+    fn sql(select: impl QueryTrait) -> String {
+        select.build(DbBackend::Postgres).to_string()
+    }
+
+    #[test]
+    fn apply_query_first_page() {
+        let select = cake::Entity::find().apply_query(&list_query(&[]));
+        assert_eq!(
+            sql(select),
+            r#"SELECT "cake"."id", "cake"."name", "cake"."note", "cake"."secret", "cake"."created_at" FROM "cake" WHERE TRUE LIMIT 20 OFFSET 0"#
+        );
+    }
+
+    #[test]
+    fn apply_query_filter_sort_and_fields() {
+        let query = list_query(&[
+            ("name", "Pancake"),
+            ("sort", "-created_at,id"),
+            ("fields", "id,name"),
+        ]);
+        assert_eq!(
+            sql(cake::Entity::find().apply_query(&query)),
+            r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."name" = 'Pancake' ORDER BY "cake"."created_at" DESC, "cake"."id" ASC LIMIT 20 OFFSET 0"#
+        );
+    }
+
+    #[test]
+    fn apply_query_ignores_unknown_fields() {
+        let query = list_query(&[
+            ("secret", "s3cr3t"),
+            ("sort", "secret,-note"),
+            ("fields", "secret,note"),
+        ]);
+        assert_eq!(
+            sql(cake::Entity::find().apply_query(&query)),
+            sql(cake::Entity::find().apply_query(&list_query(&[])))
+        );
+    }
+
+    #[test]
+    fn apply_query_max_per_page() {
+        let query = list_query(&[("page", "3"), ("per_page", "1000")]);
+        assert_eq!(query.per_page(), MAX_PER_PAGE);
+        assert!(sql(cake::Entity::find().apply_query(&query)).ends_with("LIMIT 100 OFFSET 200"));
+    }
+
+    #[test]
+    fn apply_query_select_two_keeps_related_columns() {
+        let query = list_query(&[("sort", "name"), ("fields", "id"), ("page", "2")]);
+        let select = cake::Entity::find()
+            .select_also(cake::Entity)
+            .apply_query(&query);
+        let sql = sql(select);
+        assert!(sql.contains(r#""cake"."name" AS "B_name""#), "{sql}");
+        assert!(
+            sql.ends_with(r#"WHERE TRUE ORDER BY "cake"."name" ASC LIMIT 20 OFFSET 20"#),
+            "{sql}"
+        );
+    }
+
+    fn count_row(num_items: i64) -> BTreeMap<&'static str, Value> {
+        BTreeMap::from([("num_items", num_items.into())])
+    }
+
+    #[test]
+    fn fetch_page() {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[count_row(21)]])
+            .append_query_results([[cake::model()]])
+            .into_connection();
+        let query = list_query(&[("name", "Pancake"), ("page", "2"), ("fields", "id")]);
+
+        let page =
+            futures::executor::block_on(cake::Entity::find().fetch_page(&db, &query)).unwrap();
+        assert_eq!(
+            page,
+            Page {
+                items: vec![cake::model()],
+                page: 2,
+                per_page: 20,
+                total_items: 21,
+                total_pages: 2,
+            }
+        );
+
+        // Full models are fetched (without field selection).
+        let log = db.into_transaction_log();
+        assert_eq!(
+            log[1],
+            Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"SELECT "cake"."id", "cake"."name", "cake"."note", "cake"."secret", "cake"."created_at" FROM "cake" WHERE "cake"."name" = $1 LIMIT $2 OFFSET $3"#,
+                ["Pancake".into(), 20u64.into(), 20u64.into()],
+            )
+        );
+    }
+
+    #[test]
+    fn fetch_page_select_two() {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[count_row(0)]])
+            .append_query_results([Vec::<BTreeMap<&str, Value>>::new()])
+            .into_connection();
+        let query = list_query(&[("sort", "-id")]);
+
+        let page = futures::executor::block_on(
+            cake::Entity::find()
+                .select_also(cake::Entity)
+                .fetch_page(&db, &query),
+        )
+        .unwrap();
+        assert!(page.items.is_empty());
+        assert_eq!((page.page, page.total_items, page.total_pages), (1, 0, 0));
+
+        let log = format!("{:?}", db.into_transaction_log());
+        assert!(
+            log.contains(r#"ORDER BY \"cake\".\"id\" DESC LIMIT $1 OFFSET $2"#),
+            "{log}"
+        );
+    }
+}
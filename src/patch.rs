@@ -0,0 +1,103 @@
+use sea_orm::{ActiveValue, IntoActiveValue, Value};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The value of a nullable field in a `PatchModel` (derived with [`DerivePatchModel`]).
+///
+/// A `PatchValue` distinguishes a field that is absent from a patch (the column is left unchanged)
+/// from a field that is explicitly set to `null` (the column is set to `NULL`):
+///
+/// | JSON              | `PatchValue`            | `ActiveValue`       |
+/// |-------------------|-------------------------|---------------------|
+/// | `{}`              | [`PatchValue::Absent`]  | `NotSet`            |
+/// | `{"note": null}`  | [`PatchValue::Null`]    | `Set(None)`         |
+/// | `{"note": "hi"}`  | [`PatchValue::Value`]   | `Set(Some("hi"))`   |
+///
+/// A missing field only deserializes to [`PatchValue::Absent`] with `#[serde(default)]` (which
+/// [`DerivePatchModel`] adds to `PatchValue` fields).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PatchValue<T> {
+    /// The field is absent from the patch and the column is left unchanged.
+    #[default]
+    Absent,
+    /// The field is set to `null`.
+    Null,
+    /// The field is set to a value.
+    Value(T),
+}
+
+impl<T> PatchValue<T> {
+    /// Returns true if the field is absent from the patch.
+    #[must_use]
+    pub fn is_absent(&self) -> bool {
+        matches!(self, Self::Absent)
+    }
+
+    /// Returns true if the field is set to `null`.
+    #[must_use]
+    pub fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+
+    /// Returns true if the field is set to a value.
+    #[must_use]
+    pub fn is_value(&self) -> bool {
+        matches!(self, Self::Value(_))
+    }
+
+    /// Converts from `&PatchValue<T>` to `PatchValue<&T>`.
+    #[must_use]
+    pub fn as_ref(&self) -> PatchValue<&T> {
+        match self {
+            Self::Absent => PatchValue::Absent,
+            Self::Null => PatchValue::Null,
+            Self::Value(value) => PatchValue::Value(value),
+        }
+    }
+
+    /// Returns `None` if the field is absent, otherwise the new (nullable) value of the field.
+    #[must_use]
+    pub fn into_option(self) -> Option<Option<T>> {
+        match self {
+            Self::Absent => None,
+            Self::Null => Some(None),
+            Self::Value(value) => Some(Some(value)),
+        }
+    }
+}
+
+/// `None` is converted to [`PatchValue::Null`] (not [`PatchValue::Absent`]).
+impl<T> From<Option<T>> for PatchValue<T> {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Self::Null, Self::Value)
+    }
+}
+
+impl<T> IntoActiveValue<Option<T>> for PatchValue<T>
+where
+    Option<T>: Into<Value>,
+{
+    fn into_active_value(self) -> ActiveValue<Option<T>> {
+        match self {
+            Self::Absent => ActiveValue::NotSet,
+            Self::Null => ActiveValue::Set(None),
+            Self::Value(value) => ActiveValue::Set(Some(value)),
+        }
+    }
+}
+
+/// [`PatchValue::Absent`] is serialized as `null`; skip it with
+/// `#[serde(skip_serializing_if = "PatchValue::is_absent")]`.
+impl<T: Serialize> Serialize for PatchValue<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Absent | Self::Null => serializer.serialize_none(),
+            Self::Value(value) => serializer.serialize_some(value),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for PatchValue<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Option::<T>::deserialize(deserializer).map(Self::from)
+    }
+}
@@ -0,0 +1,506 @@
+use std::fmt;
+
+use sea_orm::{EntityTrait, IdenStatic, Iterable, PrimaryKeyToColumn};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{PatchModelTrait, Resource};
+
+/// Media type of JSON Merge Patch documents ([RFC 7396](https://www.rfc-editor.org/rfc/rfc7396)).
+pub const MERGE_PATCH_MEDIA_TYPE: &str = "application/merge-patch+json";
+
+/// Media type of JSON Patch documents ([RFC 6902](https://www.rfc-editor.org/rfc/rfc6902)).
+pub const JSON_PATCH_MEDIA_TYPE: &str = "application/json-patch+json";
+
+/// A JSON Merge Patch or JSON Patch document that can be applied to a [`Resource`]'s data model.
+///
+/// The patch is applied to the JSON serialization of the resource's [`View`](Resource::View) (the
+/// representation clients see), so patched field names are the view's serialized field names
+/// (e.g. after `#[skipper(view_rename = "...")]`). [`apply()`](Self::apply) then compares the
+/// patched JSON with the view field by field and returns a `PatchModel` with the changed fields,
+/// mapped with [`PatchModelTrait::document_fields()`].
+///
+/// Only the fields of the `PatchModel` can be changed: patches changing other fields (e.g. a
+/// primary key or `read_only` field) are rejected. Hidden and write-only fields are not in the
+/// view, so their values can't be read (e.g. with a JSON Patch `test` operation), but they can be
+/// set by adding them (e.g. with an `add` operation).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PatchDocument {
+    /// A JSON Merge Patch ([RFC 7396](https://www.rfc-editor.org/rfc/rfc7396)) document.
+    MergePatch(Value),
+    /// A JSON Patch ([RFC 6902](https://www.rfc-editor.org/rfc/rfc6902)) document.
+    JsonPatch(json_patch::Patch),
+}
+
+impl PatchDocument {
+    /// Parses a patch document from a request `body` based on its `content_type`.
+    ///
+    /// `content_type` must be [`MERGE_PATCH_MEDIA_TYPE`] or [`JSON_PATCH_MEDIA_TYPE`] (parameters
+    /// such as `charset` are ignored).
+    ///
+    /// # Errors
+    /// Returns [`PatchError::UnsupportedMediaType`] for other content types and
+    /// [`PatchError::Parse`] if `body` is not a valid document.
+    pub fn from_slice(content_type: &str, body: &[u8]) -> Result<Self, PatchError> {
+        let media_type = content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        match media_type.as_str() {
+            MERGE_PATCH_MEDIA_TYPE => serde_json::from_slice(body)
+                .map(Self::MergePatch)
+                .map_err(PatchError::Parse),
+            JSON_PATCH_MEDIA_TYPE => serde_json::from_slice(body)
+                .map(Self::JsonPatch)
+                .map_err(PatchError::Parse),
+            _ => Err(PatchError::UnsupportedMediaType(content_type.to_string())),
+        }
+    }
+
+    /// Applies the patch document to a JSON `document` in place.
+    ///
+    /// # Errors
+    /// Returns [`PatchError::Apply`] if a JSON Patch operation fails (e.g. a `test` operation or a
+    /// missing path). `document` is left unchanged when an operation fails.
+    pub fn apply_to_value(&self, document: &mut Value) -> Result<(), PatchError> {
+        match self {
+            Self::MergePatch(patch) => {
+                json_patch::merge(document, patch);
+                Ok(())
+            }
+            Self::JsonPatch(patch) => json_patch::patch(document, patch).map_err(PatchError::Apply),
+        }
+    }
+
+    /// Applies the patch document to the `View` of a resource's `data` and returns a `PatchModel`
+    /// `P` with the changed fields (other fields are absent).
+    ///
+    /// Fields that are removed by the patch are set to `null`. The returned `PatchModel` can be
+    /// validated and converted into an `ActiveModel` to update the record like a `PatchModel`
+    /// received in a request body.
+    ///
+    /// # Errors
+    /// Returns a [`PatchError`] if the patch can't be applied, the patch changes a field that is
+    /// not in `P` (e.g. the primary key or a `read_only` field), or the changed fields do not
+    /// deserialize into `P`.
+    pub fn apply<P>(&self, data: &<P::Entity as Resource>::Data) -> Result<P, PatchError>
+    where
+        P: PatchModelTrait + DeserializeOwned + Serialize,
+        P::Entity: Resource,
+    {
+        let view = <P::Entity as Resource>::View::from(data.clone());
+        let original = serde_json::to_value(view).map_err(PatchError::Invalid)?;
+        let mut document = original.clone();
+        self.apply_to_value(&mut document)?;
+        let (Value::Object(original), Value::Object(mut patched)) = (original, document) else {
+            return Err(PatchError::Invalid(serde::de::Error::custom(
+                "the patched resource is not a JSON object",
+            )));
+        };
+        for name in original.keys() {
+            patched.entry(name.clone()).or_insert(Value::Null);
+        }
+
+        let mut changes = Map::new();
+        // Names of the changed fields in the patch document & in `P`.
+        let mut changed_fields = Vec::new();
+        for (name, value) in patched {
+            if original.get(&name).unwrap_or(&Value::Null) == &value {
+                continue;
+            }
+            let Some((document_name, patch_name)) = P::document_fields()
+                .iter()
+                .find(|(document_name, _)| *document_name == name)
+            else {
+                let is_primary_key = <P::Entity as EntityTrait>::PrimaryKey::iter()
+                    .any(|key| key.into_column().as_str() == name);
+                return Err(if is_primary_key {
+                    PatchError::PrimaryKey(name)
+                } else {
+                    PatchError::ReadOnly(name)
+                });
+            };
+            changed_fields.push((*document_name, *patch_name));
+            changes.insert((*patch_name).to_string(), value);
+        }
+
+        let patch_model: P =
+            serde_json::from_value(Value::Object(changes)).map_err(PatchError::Invalid)?;
+        // A `null` value of a field that isn't nullable deserializes as an absent field.
+        let serialized = serde_json::to_value(&patch_model).map_err(PatchError::Invalid)?;
+        if let Some((document_name, _)) = changed_fields
+            .iter()
+            .find(|(_, patch_name)| serialized.get(patch_name).is_none())
+        {
+            return Err(PatchError::Invalid(serde::de::Error::custom(format!(
+                "`{document_name}` can't be null"
+            ))));
+        }
+        Ok(patch_model)
+    }
+}
+
+impl From<json_patch::Patch> for PatchDocument {
+    fn from(patch: json_patch::Patch) -> Self {
+        Self::JsonPatch(patch)
+    }
+}
+
+/// An error parsing or applying a [`PatchDocument`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PatchError {
+    /// The content type is not a supported patch document media type.
+    UnsupportedMediaType(String),
+    /// The patch document is not valid JSON (or not a valid JSON Patch).
+    Parse(serde_json::Error),
+    /// A JSON Patch operation failed.
+    Apply(json_patch::PatchError),
+    /// The patched JSON does not deserialize into the model.
+    Invalid(serde_json::Error),
+    /// The patch changes the named primary key column.
+    PrimaryKey(String),
+    /// The patch changes the named field, which is not a field of the `PatchModel` (e.g. a
+    /// `read_only` field).
+    ReadOnly(String),
+}
+
+impl PatchError {
+    /// Returns true if the patch document is well-formed but can't be applied to the model (e.g.
+    /// the patched model is invalid), which is a `422 Unprocessable Entity` in HTTP APIs.
+    #[must_use]
+    pub fn is_unprocessable(&self) -> bool {
+        matches!(
+            self,
+            Self::Apply(_) | Self::Invalid(_) | Self::PrimaryKey(_) | Self::ReadOnly(_)
+        )
+    }
+}
+
+impl fmt::Display for PatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedMediaType(content_type) => {
+                write!(f, "unsupported patch media type `{content_type}`")
+            }
+            Self::Parse(err) => write!(f, "invalid patch document: {err}"),
+            Self::Apply(err) => write!(f, "failed to apply patch: {err}"),
+            Self::Invalid(err) => write!(f, "patched model is invalid: {err}"),
+            Self::PrimaryKey(column) => write!(f, "primary key column `{column}` can't be patched"),
+            Self::ReadOnly(field) => write!(f, "read-only field `{field}` can't be patched"),
+        }
+    }
+}
+
+impl std::error::Error for PatchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Parse(err) | Self::Invalid(err) => Some(err),
+            Self::Apply(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use super::*;
+    use crate::test_entity::cake;
+    use crate::PatchValue;
+
+    /// A `PatchModel` of cakes like `DerivePatchModel` would derive with a `read_only`
+    /// `created_at` and a write-only `secret` (the cake `View` leaves `secret` out).
+    #[derive(Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+    struct CakePatch {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+        #[serde(default, skip_serializing_if = "PatchValue::is_absent")]
+        note: PatchValue<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        secret: Option<String>,
+    }
+
+    impl PatchModelTrait for CakePatch {
+        type Entity = cake::Entity;
+
+        fn read_only_columns() -> &'static [cake::Column] {
+            &[cake::Column::CreatedAt]
+        }
+
+        fn document_fields() -> &'static [(&'static str, &'static str)] {
+            &[("name", "name"), ("note", "note"), ("secret", "secret")]
+        }
+    }
+
+    /// An entity whose `View` renames a field.
+    mod account {
+        use sea_orm::entity::prelude::*;
+        use serde::{Deserialize, Serialize};
+
+        use crate::PatchModelTrait;
+
+        #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Deserialize, Serialize)]
+        #[sea_orm(table_name = "account")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub email: String,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+
+        /// Like a `ViewModel` with `#[skipper(view_rename = "login")]` on `email`.
+        #[derive(Serialize)]
+        pub struct View {
+            pub id: i32,
+            pub login: String,
+        }
+
+        impl From<Model> for View {
+            fn from(model: Model) -> Self {
+                Self {
+                    id: model.id,
+                    login: model.email,
+                }
+            }
+        }
+
+        impl crate::Resource for Entity {
+            type ActiveModel = ActiveModel;
+            type Data = Model;
+            type View = View;
+            type Id = i32;
+        }
+
+        #[derive(Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+        pub struct Patch {
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            pub email: Option<String>,
+        }
+
+        impl PatchModelTrait for Patch {
+            type Entity = Entity;
+
+            fn read_only_columns() -> &'static [Column] {
+                &[]
+            }
+
+            fn document_fields() -> &'static [(&'static str, &'static str)] {
+                &[("login", "email")]
+            }
+        }
+    }
+
+    fn merge_patch(patch: serde_json::Value) -> PatchDocument {
+        PatchDocument::MergePatch(patch)
+    }
+
+    fn json_patch(patch: serde_json::Value) -> PatchDocument {
+        PatchDocument::JsonPatch(serde_json::from_value(patch).unwrap())
+    }
+
+    #[test]
+    fn from_slice_merge_patch() {
+        let document = PatchDocument::from_slice(MERGE_PATCH_MEDIA_TYPE, br#"{"name":"Tea Cake"}"#);
+        assert_eq!(
+            document.unwrap(),
+            merge_patch(json!({ "name": "Tea Cake" }))
+        );
+    }
+
+    #[test]
+    fn from_slice_json_patch() {
+        let body = br#"[{"op":"replace","path":"/name","value":"Tea Cake"}]"#;
+        let document = PatchDocument::from_slice(JSON_PATCH_MEDIA_TYPE, body);
+        assert_eq!(
+            document.unwrap(),
+            json_patch(json!([{ "op": "replace", "path": "/name", "value": "Tea Cake" }]))
+        );
+    }
+
+    #[test]
+    fn from_slice_media_type_parameters() {
+        let document = PatchDocument::from_slice(
+            "Application/Merge-Patch+JSON; charset=utf-8",
+            br#"{"note":null}"#,
+        );
+        assert_eq!(document.unwrap(), merge_patch(json!({ "note": null })));
+    }
+
+    #[test]
+    fn from_slice_unsupported_media_type() {
+        let err = PatchDocument::from_slice("application/json", b"{}").unwrap_err();
+        assert!(
+            matches!(err, PatchError::UnsupportedMediaType(media_type) if media_type == "application/json")
+        );
+    }
+
+    #[test]
+    fn from_slice_invalid_document() {
+        let err =
+            PatchDocument::from_slice(JSON_PATCH_MEDIA_TYPE, br#"{"op":"nope"}"#).unwrap_err();
+        assert!(matches!(err, PatchError::Parse(_)));
+        assert!(!err.is_unprocessable());
+    }
+
+    #[test]
+    fn apply_merge_patch() {
+        let patch: CakePatch = merge_patch(json!({ "name": "Tea Cake", "note": "fresh" }))
+            .apply(&cake::model())
+            .unwrap();
+        assert_eq!(
+            patch,
+            CakePatch {
+                name: Some("Tea Cake".to_string()),
+                note: PatchValue::Value("fresh".to_string()),
+                secret: None,
+            }
+        );
+    }
+
+    #[test]
+    fn apply_merge_patch_null() {
+        let mut model = cake::model();
+        model.note = Some("fresh".to_string());
+        let patch: CakePatch = merge_patch(json!({ "note": null })).apply(&model).unwrap();
+        assert_eq!(
+            patch,
+            CakePatch {
+                note: PatchValue::Null,
+                ..CakePatch::default()
+            }
+        );
+    }
+
+    #[test]
+    fn apply_json_patch() {
+        let patch: CakePatch = json_patch(json!([
+            { "op": "test", "path": "/name", "value": "Pancake" },
+            { "op": "replace", "path": "/name", "value": "Tea Cake" },
+        ]))
+        .apply(&cake::model())
+        .unwrap();
+        assert_eq!(
+            patch,
+            CakePatch {
+                name: Some("Tea Cake".to_string()),
+                ..CakePatch::default()
+            }
+        );
+    }
+
+    #[test]
+    fn apply_failed_json_patch_operation() {
+        let err = json_patch(json!([{ "op": "test", "path": "/name", "value": "Tea Cake" }]))
+            .apply::<CakePatch>(&cake::model())
+            .unwrap_err();
+        assert!(matches!(err, PatchError::Apply(_)));
+        assert!(err.is_unprocessable());
+    }
+
+    #[test]
+    fn apply_leaves_unchanged_fields_absent() {
+        let model = cake::model();
+        let patch: CakePatch = merge_patch(json!({
+            "id": 1,
+            "name": "Pancake",
+            "created_at": 1_700_000_000,
+        }))
+        .apply(&model)
+        .unwrap();
+        assert_eq!(patch, CakePatch::default());
+    }
+
+    #[test]
+    fn apply_does_not_reveal_fields_that_are_not_in_the_view() {
+        // `secret` is write-only: it can't be tested, but it can be added.
+        let err = json_patch(json!([{ "op": "test", "path": "/secret", "value": "s3cr3t" }]))
+            .apply::<CakePatch>(&cake::model())
+            .unwrap_err();
+        assert!(matches!(err, PatchError::Apply(_)));
+
+        let patch: CakePatch =
+            json_patch(json!([{ "op": "add", "path": "/secret", "value": "n3w" }]))
+                .apply(&cake::model())
+                .unwrap();
+        assert_eq!(patch.secret, Some("n3w".to_string()));
+    }
+
+    #[test]
+    fn apply_uses_view_names() {
+        let model = account::Model {
+            id: 7,
+            email: "a@example.com".to_string(),
+        };
+        let patch: account::Patch = merge_patch(json!({ "login": "b@example.com" }))
+            .apply(&model)
+            .unwrap();
+        assert_eq!(patch.email, Some("b@example.com".to_string()));
+
+        let err = merge_patch(json!({ "email": "b@example.com" }))
+            .apply::<account::Patch>(&model)
+            .unwrap_err();
+        assert!(matches!(err, PatchError::ReadOnly(field) if field == "email"));
+    }
+
+    #[test]
+    fn apply_rejects_primary_key_change() {
+        let err = merge_patch(json!({ "id": 2 }))
+            .apply::<CakePatch>(&cake::model())
+            .unwrap_err();
+        assert!(matches!(err, PatchError::PrimaryKey(column) if column == "id"));
+    }
+
+    #[test]
+    fn apply_rejects_read_only_field_change() {
+        let err = json_patch(json!([{ "op": "replace", "path": "/created_at", "value": 0 }]))
+            .apply::<CakePatch>(&cake::model())
+            .unwrap_err();
+        assert!(matches!(err, PatchError::ReadOnly(ref field) if field == "created_at"));
+        assert!(err.is_unprocessable());
+    }
+
+    #[test]
+    fn apply_rejects_unknown_field() {
+        let err = merge_patch(json!({ "flavor": "lemon" }))
+            .apply::<CakePatch>(&cake::model())
+            .unwrap_err();
+        assert!(matches!(err, PatchError::ReadOnly(field) if field == "flavor"));
+    }
+
+    #[test]
+    fn apply_rejects_null_of_field_that_is_not_nullable() {
+        let err = merge_patch(json!({ "name": null }))
+            .apply::<CakePatch>(&cake::model())
+            .unwrap_err();
+        assert!(matches!(err, PatchError::Invalid(_)));
+        assert_eq!(
+            err.to_string(),
+            "patched model is invalid: `name` can't be null"
+        );
+    }
+
+    #[test]
+    fn apply_invalid_value() {
+        let err = merge_patch(json!({ "name": 7 }))
+            .apply::<CakePatch>(&cake::model())
+            .unwrap_err();
+        assert!(matches!(err, PatchError::Invalid(_)));
+    }
+
+    #[test]
+    fn apply_replaced_document() {
+        let err = json_patch(json!([{ "op": "replace", "path": "", "value": "cake" }]))
+            .apply::<CakePatch>(&cake::model())
+            .unwrap_err();
+        assert!(matches!(err, PatchError::Invalid(_)));
+    }
+}
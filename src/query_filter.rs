@@ -45,6 +45,14 @@ impl<P: QueryParams + Debug> QueryFilter<P> {
                 .collect(),
         )
     }
+
+    /// Returns an `ALL` [`Condition`] for this [`QueryFilter`] without consuming it.
+    ///
+    /// See the [`IntoCondition`] implementation for details on the produced [`Condition`].
+    #[must_use]
+    pub fn to_condition(&self) -> Condition {
+        Self(self.0.clone()).into_condition()
+    }
 }
 
 /// Allows [`QueryFilter`] to be converted into an `ALL` [`Condition`] for database queries.
@@ -0,0 +1,284 @@
+use std::fmt;
+use std::fmt::Write;
+
+/// A value of a single primary key column that can be parsed from and rendered into a URL path
+/// segment.
+///
+/// This is implemented for the integer types, `bool`, `char` and `String` (and `uuid::Uuid` with the
+/// `with-uuid` feature, parsed from any of its text formats and rendered hyphenated).
+pub trait IdSegment: Sized {
+    /// Parses a (percent-decoded) path `segment`, returning `None` if it is not a valid value.
+    fn from_segment(segment: &str) -> Option<Self>;
+
+    /// Renders the value into a path segment (before percent-encoding).
+    fn to_segment(&self) -> String;
+}
+
+macro_rules! impl_id_segment {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl IdSegment for $ty {
+                fn from_segment(segment: &str) -> Option<Self> {
+                    segment.parse().ok()
+                }
+
+                fn to_segment(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )+
+    };
+}
+
+impl_id_segment!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, bool, char, String);
+
+#[cfg(feature = "with-uuid")]
+impl_id_segment!(uuid::Uuid);
+
+/// The [`Id`](crate::Resource::Id) of a [`Resource`](crate::Resource) parsed from and rendered into
+/// URL path segments (one segment per primary key column).
+///
+/// This is implemented for [`IdSegment`] types (single column primary keys) and for tuples of
+/// [`IdSegment`] types with up to 12 elements (composite primary keys, as supported by `sea-orm`).
+///
+/// ```
+/// use sea_skipper::ResourceId;
+///
+/// let id = <(i32, String)>::from_segments(&["7", "a slug"]).unwrap();
+/// assert_eq!(id, (7, "a slug".to_owned()));
+/// assert_eq!(id.to_path(), "7/a%20slug");
+/// ```
+pub trait ResourceId: Sized {
+    /// The number of primary key columns (and path segments) of the id.
+    const ARITY: usize;
+
+    /// Parses the id from (percent-decoded) path `segments`.
+    ///
+    /// # Errors
+    /// Returns a [`ResourceIdError`] if the number of `segments` is not [`Self::ARITY`] or if a
+    /// segment is not a valid value of its primary key column.
+    fn from_segments<S: AsRef<str>>(segments: &[S]) -> Result<Self, ResourceIdError>;
+
+    /// Renders the id into path segments (before percent-encoding).
+    fn to_segments(&self) -> Vec<String>;
+
+    /// Renders the id into a path with its percent-encoded segments joined by `/` (e.g. to build
+    /// the `Location` of a resource).
+    fn to_path(&self) -> String {
+        self.to_segments()
+            .iter()
+            .map(|segment| encode_segment(segment))
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+}
+
+impl<T: IdSegment> ResourceId for T {
+    const ARITY: usize = 1;
+
+    fn from_segments<S: AsRef<str>>(segments: &[S]) -> Result<Self, ResourceIdError> {
+        match segments {
+            [segment] => parse_segment(0, segment.as_ref()),
+            _ => Err(ResourceIdError::Arity {
+                expected: Self::ARITY,
+                found: segments.len(),
+            }),
+        }
+    }
+
+    fn to_segments(&self) -> Vec<String> {
+        vec![self.to_segment()]
+    }
+}
+
+macro_rules! impl_resource_id_tuple {
+    ($arity:literal, $($idx:tt : $T:ident),+) => {
+        impl<$($T: IdSegment),+> ResourceId for ($($T),+) {
+            const ARITY: usize = $arity;
+
+            fn from_segments<S: AsRef<str>>(segments: &[S]) -> Result<Self, ResourceIdError> {
+                if segments.len() != Self::ARITY {
+                    return Err(ResourceIdError::Arity {
+                        expected: Self::ARITY,
+                        found: segments.len(),
+                    });
+                }
+                Ok(($(parse_segment::<$T>($idx, segments[$idx].as_ref())?),+))
+            }
+
+            fn to_segments(&self) -> Vec<String> {
+                vec![$(self.$idx.to_segment()),+]
+            }
+        }
+    };
+}
+
+#[rustfmt::skip]
+mod impl_resource_id_tuple {
+    use super::{parse_segment, IdSegment, ResourceId, ResourceIdError};
+
+    impl_resource_id_tuple!( 2, 0:T0, 1:T1);
+    impl_resource_id_tuple!( 3, 0:T0, 1:T1, 2:T2);
+    impl_resource_id_tuple!( 4, 0:T0, 1:T1, 2:T2, 3:T3);
+    impl_resource_id_tuple!( 5, 0:T0, 1:T1, 2:T2, 3:T3, 4:T4);
+    impl_resource_id_tuple!( 6, 0:T0, 1:T1, 2:T2, 3:T3, 4:T4, 5:T5);
+    impl_resource_id_tuple!( 7, 0:T0, 1:T1, 2:T2, 3:T3, 4:T4, 5:T5, 6:T6);
+    impl_resource_id_tuple!( 8, 0:T0, 1:T1, 2:T2, 3:T3, 4:T4, 5:T5, 6:T6, 7:T7);
+    impl_resource_id_tuple!( 9, 0:T0, 1:T1, 2:T2, 3:T3, 4:T4, 5:T5, 6:T6, 7:T7, 8:T8);
+    impl_resource_id_tuple!(10, 0:T0, 1:T1, 2:T2, 3:T3, 4:T4, 5:T5, 6:T6, 7:T7, 8:T8, 9:T9);
+    impl_resource_id_tuple!(11, 0:T0, 1:T1, 2:T2, 3:T3, 4:T4, 5:T5, 6:T6, 7:T7, 8:T8, 9:T9, 10:T10);
+    impl_resource_id_tuple!(12, 0:T0, 1:T1, 2:T2, 3:T3, 4:T4, 5:T5, 6:T6, 7:T7, 8:T8, 9:T9, 10:T10, 11:T11);
+}
+
+/// Parses the path `segment` at `index` of an id.
+fn parse_segment<T: IdSegment>(index: usize, segment: &str) -> Result<T, ResourceIdError> {
+    T::from_segment(segment).ok_or_else(|| ResourceIdError::InvalidSegment {
+        index,
+        segment: segment.to_owned(),
+    })
+}
+
+/// Percent-encodes all characters of a path `segment` except unreserved characters (RFC 3986).
+pub(crate) fn encode_segment(segment: &str) -> String {
+    let mut encoded = String::with_capacity(segment.len());
+    for byte in segment.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(char::from(byte));
+            }
+            _ => {
+                let _ = write!(encoded, "%{byte:02X}");
+            }
+        }
+    }
+    encoded
+}
+
+/// An error parsing a [`ResourceId`] from path segments.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ResourceIdError {
+    /// The number of path segments does not match the number of primary key columns.
+    Arity { expected: usize, found: usize },
+    /// The path segment at `index` is not a valid value of its primary key column.
+    InvalidSegment { index: usize, segment: String },
+}
+
+impl fmt::Display for ResourceIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Arity { expected, found } => {
+                write!(f, "expected {expected} id path segment(s), found {found}")
+            }
+            Self::InvalidSegment { index, segment } => {
+                write!(f, "invalid id path segment {index}: `{segment}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ResourceIdError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_segment_keeps_unreserved_characters() {
+        assert_eq!(encode_segment("Az09-._~"), "Az09-._~");
+        assert_eq!(encode_segment(""), "");
+    }
+
+    #[test]
+    fn encode_segment_percent_encodes() {
+        assert_eq!(encode_segment("a b/c?d#e%"), "a%20b%2Fc%3Fd%23e%25");
+        assert_eq!(encode_segment("{id}"), "%7Bid%7D");
+        // Non-ASCII characters are encoded byte by byte (UTF-8).
+        assert_eq!(encode_segment("é"), "%C3%A9");
+        assert_eq!(encode_segment("🍰"), "%F0%9F%8D%B0");
+    }
+
+    #[test]
+    fn single_segment() {
+        assert_eq!(i32::from_segments(&["-7"]), Ok(-7));
+        assert_eq!(String::from_segments(&["a slug"]), Ok("a slug".to_owned()));
+        assert_eq!(42_u64.to_path(), "42");
+        assert_eq!("crème".to_owned().to_path(), "cr%C3%A8me");
+        assert_eq!(
+            i128::from_segments(&["-170141183460469231731687303715884105728"]),
+            Ok(i128::MIN)
+        );
+        assert_eq!(
+            u128::MAX.to_path(),
+            "340282366920938463463374607431768211455"
+        );
+    }
+
+    #[cfg(feature = "with-uuid")]
+    #[test]
+    fn uuid_segment() {
+        let uuid = uuid::Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8);
+        assert_eq!(uuid.to_path(), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+        assert_eq!(
+            uuid::Uuid::from_segments(&["67e5504410b1426f9247bb680e5fe0c8"]),
+            Ok(uuid)
+        );
+        assert_eq!(
+            uuid::Uuid::from_segments(&["67e55044"]),
+            Err(ResourceIdError::InvalidSegment {
+                index: 0,
+                segment: "67e55044".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn composite_segments() {
+        let id = <(i32, String, bool)>::from_segments(&["7", "x/y", "true"]).unwrap();
+        assert_eq!(id, (7, "x/y".to_owned(), true));
+        assert_eq!(id.to_segments(), ["7", "x/y", "true"]);
+        assert_eq!(id.to_path(), "7/x%2Fy/true");
+    }
+
+    #[test]
+    fn wrong_arity() {
+        assert_eq!(
+            i32::from_segments(&["1", "2"]),
+            Err(ResourceIdError::Arity {
+                expected: 1,
+                found: 2
+            })
+        );
+        assert_eq!(
+            <(i32, i32)>::from_segments::<&str>(&[]),
+            Err(ResourceIdError::Arity {
+                expected: 2,
+                found: 0
+            })
+        );
+        assert_eq!(
+            <(i32, i32)>::from_segments(&["1"]).unwrap_err().to_string(),
+            "expected 2 id path segment(s), found 1"
+        );
+    }
+
+    #[test]
+    fn invalid_segment() {
+        assert_eq!(
+            u8::from_segments(&["256"]),
+            Err(ResourceIdError::InvalidSegment {
+                index: 0,
+                segment: "256".to_owned()
+            })
+        );
+        let err = <(i32, char)>::from_segments(&["1", "ab"]).unwrap_err();
+        assert_eq!(
+            err,
+            ResourceIdError::InvalidSegment {
+                index: 1,
+                segment: "ab".to_owned()
+            }
+        );
+        assert_eq!(err.to_string(), "invalid id path segment 1: `ab`");
+    }
+}
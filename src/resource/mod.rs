@@ -1,21 +1,28 @@
 //! Traits describing API [`Resource`]s and [`data`].
 mod data;
+mod id;
 
 use std::fmt::Debug;
 
 use sea_orm::{
-    ActiveModelBehavior, ActiveModelTrait, EntityTrait, IntoActiveModel, ModelTrait,
-    PrimaryKeyTrait,
+    sea_query::IntoValueTuple, ActiveModelBehavior, ActiveModelTrait, ColumnTrait, Condition,
+    DeleteMany, EntityTrait, IntoActiveModel, Iterable, ModelTrait, PrimaryKeyToColumn,
+    PrimaryKeyTrait, QueryFilter, Select,
 };
 use serde::Serialize;
 
+// `Location` is part of the resource API (the crate root also re-exports it from `location`).
+#[allow(unused_imports)]
 pub use crate::Location;
 pub use data::*;
+pub use id::*;
 
 /// A type that encapsulates `sea-orm` entity types and captures their trait bounds.
 ///
 /// This may be used to simplify the trait bounds when passing `sea-orm` model types as generics in
 /// functions that perform database operations using `sea-orm`.
+///
+/// Use [`DeriveResource`] (with the `derive` feature) to implement it from a `Model`'s primary key.
 pub trait Resource: EntityTrait<Model = Self::Data> {
     /// The `ActiveModel` type from the `sea-orm` entity of the [`Resource`].
     type ActiveModel: ActiveModelTrait<Entity = Self> + ActiveModelBehavior + Send;
@@ -27,6 +34,130 @@ pub trait Resource: EntityTrait<Model = Self::Data> {
         + Send
         + Serialize;
 
+    /// The type that a [`Resource`]'s [`Data`](Self::Data) is converted into when it is sent in
+    /// responses (e.g. a `ViewModel` derived with [`DeriveViewModel`] leaving out hidden fields).
+    ///
+    /// Use [`Data`](Self::Data) itself if all of its fields can be sent.
+    type View: From<Self::Data> + Serialize + Send;
+
     /// Captures type of [`Data`] / [`EntityTrait::Model`]'s primary key.
+    ///
+    /// This is a tuple for composite primary keys (e.g. `(i32, String)` for a `(tenant_id, slug)`
+    /// key). Implement [`ResourceId`] to parse it from (and render it into) URL path segments.
     type Id: Into<<Self::PrimaryKey as PrimaryKeyTrait>::ValueType> + Debug;
+
+    /// A unique text column identifying the resource in URL paths as an alternative to its primary
+    /// key (e.g. a `slug` column in `/cakes/chocolate-fudge`).
+    ///
+    /// A path segment that is not a valid [`Id`](Self::Id) is looked up by this column (see
+    /// [`path_condition()`](Self::path_condition)). A segment that is a valid id is always looked
+    /// up by the primary key, so values that are valid ids (e.g. a `"42"` slug with an integer
+    /// primary key) can't identify the resource: a derived [`Location`] renders the primary key
+    /// instead of such values. The column must be unique (so a segment matches at most one record)
+    /// and hold strings (path segments are compared as strings).
+    ///
+    /// [`DeriveResource`] implements this with the `#[skipper(alternate_key)]` field of a `Model`.
+    #[must_use]
+    fn alternate_key() -> Option<Self::Column> {
+        None
+    }
+
+    /// Returns a [`Condition`] selecting the resource identified by path `segments`.
+    ///
+    /// Segments that are a valid [`Id`](Self::Id) (one per primary key column) select the resource
+    /// by its primary key. Otherwise, a single segment selects the resource whose
+    /// [`alternate_key()`](Self::alternate_key) is equal to it. Either way, the condition matches
+    /// at most one record.
+    ///
+    /// # Errors
+    /// Returns a [`ResourceIdError`] if `segments` are not a valid [`Id`](Self::Id) and can't be
+    /// looked up by an alternate key.
+    fn path_condition<S: AsRef<str>>(segments: &[S]) -> Result<Condition, ResourceIdError>
+    where
+        Self::Id: ResourceId,
+    {
+        let err = match Self::Id::from_segments(segments) {
+            Ok(id) => {
+                let values: <Self::PrimaryKey as PrimaryKeyTrait>::ValueType = id.into();
+                return Ok(Self::PrimaryKey::iter()
+                    .zip(values.into_value_tuple())
+                    .fold(Condition::all(), |all, (key, value)| {
+                        all.add(key.into_column().eq(value))
+                    }));
+            }
+            Err(err) => err,
+        };
+        match (segments, Self::alternate_key()) {
+            ([segment], Some(column)) => Ok(Condition::all().add(column.eq(segment.as_ref()))),
+            _ => Err(err),
+        }
+    }
+
+    /// Selects the resource identified by path `segments` (its primary key or an alternate key, see
+    /// [`path_condition()`](Self::path_condition)).
+    ///
+    /// # Errors
+    /// Returns a [`ResourceIdError`] if `segments` don't identify a resource.
+    fn find_by_path_id<S: AsRef<str>>(segments: &[S]) -> Result<Select<Self>, ResourceIdError>
+    where
+        Self::Id: ResourceId,
+    {
+        Self::path_condition(segments).map(|condition| Self::find().filter(condition))
+    }
+
+    /// Deletes the resource identified by path `segments` (its primary key or an alternate key, see
+    /// [`path_condition()`](Self::path_condition)).
+    ///
+    /// # Errors
+    /// Returns a [`ResourceIdError`] if `segments` don't identify a resource.
+    fn delete_by_path_id<S: AsRef<str>>(segments: &[S]) -> Result<DeleteMany<Self>, ResourceIdError>
+    where
+        Self::Id: ResourceId,
+    {
+        Self::path_condition(segments).map(|condition| Self::delete_many().filter(condition))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{DbBackend, QueryTrait};
+
+    use super::*;
+    use crate::test_entity::cake;
+
+    fn sql(select: Select<cake::Entity>) -> String {
+        select.build(DbBackend::Postgres).to_string()
+    }
+
+    #[test]
+    fn find_by_path_id_primary_key() {
+        let select = cake::Entity::find_by_path_id(&["7"]).unwrap();
+        assert!(sql(select).ends_with(r#"WHERE "cake"."id" = 7"#));
+    }
+
+    #[test]
+    fn find_by_path_id_alternate_key() {
+        let select = cake::Entity::find_by_path_id(&["pancake"]).unwrap();
+        assert!(sql(select).ends_with(r#"WHERE "cake"."name" = 'pancake'"#));
+    }
+
+    #[test]
+    fn delete_by_path_id_alternate_key() {
+        let delete = cake::Entity::delete_by_path_id(&["pancake"]).unwrap();
+        assert_eq!(
+            delete.build(DbBackend::Postgres).to_string(),
+            r#"DELETE FROM "cake" WHERE "cake"."name" = 'pancake'"#
+        );
+    }
+
+    #[test]
+    fn path_condition_wrong_arity() {
+        assert_eq!(
+            cake::Entity::path_condition(&["7", "pancake"]).unwrap_err(),
+            ResourceIdError::Arity {
+                expected: 1,
+                found: 2
+            }
+        );
+    }
 }
@@ -0,0 +1,326 @@
+use crate::{resource::encode_segment, Location, Resource};
+
+/// A URL path template of a [`Resource`] (e.g. `"/cakes/{id}"` or `"/cakes/{cake_id}/slices/{id}"`).
+///
+/// The same template builds the resource's routes (e.g. for `axum`) and reverse-generates the URLs
+/// of its collection and items, so the two can't get out of sync:
+/// - [`item_route()`](Self::item_route) & [`collection_route()`](Self::collection_route) replace
+///   `{name}` placeholders with `:name` route parameters.
+/// - [`item_path()`](Self::item_path) & [`collection_path()`](Self::collection_path) replace
+///   placeholders with percent-encoded values.
+///
+/// The collection of a template is the template without its trailing placeholder segments, so the
+/// collection of a nested resource (e.g. `"/cakes/{cake_id}/slices"`) keeps the placeholders of
+/// its parents.
+///
+/// `{{` and `}}` are literal braces.
+///
+/// ```
+/// use sea_skipper::RouteTemplate;
+///
+/// let route = RouteTemplate::new("/cakes/{cake_id}/slices/{id}");
+/// assert_eq!(route.item_route(), "/cakes/:cake_id/slices/:id");
+/// assert_eq!(route.collection_route(), "/cakes/:cake_id/slices");
+/// assert_eq!(route.item_path(&["7", "a slice"]), "/cakes/7/slices/a%20slice");
+/// assert_eq!(route.collection_path(&["7"]), "/cakes/7/slices");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RouteTemplate(&'static str);
+
+/// A part of a [`RouteTemplate`].
+enum Part<'a> {
+    /// Literal text (with `{{` & `}}` escapes).
+    Literal(&'a str),
+    /// Name of a placeholder.
+    Param(&'a str),
+}
+
+impl RouteTemplate {
+    /// Constructs a [`RouteTemplate`] from a path `template` with `{name}` placeholders.
+    #[must_use]
+    pub const fn new(template: &'static str) -> Self {
+        Self(template)
+    }
+
+    /// Returns the path template.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        self.0
+    }
+
+    /// Returns the names of the placeholders of the item path.
+    #[must_use]
+    pub fn params(&self) -> Vec<&'static str> {
+        params(self.0)
+    }
+
+    /// Returns the names of the placeholders of the collection path (i.e. the parents' placeholders
+    /// of a nested resource).
+    #[must_use]
+    pub fn collection_params(&self) -> Vec<&'static str> {
+        params(self.collection_template())
+    }
+
+    /// Returns the route of an item (e.g. `"/cakes/:id"`).
+    #[must_use]
+    pub fn item_route(&self) -> String {
+        route(self.0)
+    }
+
+    /// Returns the route of the collection (e.g. `"/cakes"`).
+    #[must_use]
+    pub fn collection_route(&self) -> String {
+        route(self.collection_template())
+    }
+
+    /// Returns the path of an item, replacing the placeholders with percent-encoded `values` (in
+    /// order).
+    ///
+    /// # Panics
+    /// Panics if there are fewer `values` than [`params()`](Self::params).
+    #[must_use]
+    pub fn item_path<S: AsRef<str>>(&self, values: &[S]) -> String {
+        path(self.0, values)
+    }
+
+    /// Returns the path of the collection, replacing the placeholders of parent resources with
+    /// percent-encoded `values` (in order).
+    ///
+    /// # Panics
+    /// Panics if there are fewer `values` than [`collection_params()`](Self::collection_params).
+    #[must_use]
+    pub fn collection_path<S: AsRef<str>>(&self, values: &[S]) -> String {
+        path(self.collection_template(), values)
+    }
+
+    /// Returns the template without its trailing placeholder segments.
+    fn collection_template(&self) -> &'static str {
+        let mut template = self.0.trim_end_matches('/');
+        while let Some((parent, segment)) = template.rsplit_once('/') {
+            match parts(segment).as_slice() {
+                [Part::Param(_)] => template = parent,
+                _ => break,
+            }
+        }
+        template
+    }
+}
+
+/// Returns the literal & placeholder parts of a `template`.
+///
+/// An unclosed `{` is treated as literal text.
+fn parts(template: &str) -> Vec<Part<'_>> {
+    let mut parts = Vec::new();
+    let mut rest = template;
+    while !rest.is_empty() {
+        let Some(start) = rest.find('{') else {
+            parts.push(Part::Literal(rest));
+            break;
+        };
+        if rest[start..].starts_with("{{") {
+            parts.push(Part::Literal(&rest[..start + 2]));
+            rest = &rest[start + 2..];
+            continue;
+        }
+        let Some(len) = rest[start..].find('}') else {
+            parts.push(Part::Literal(rest));
+            break;
+        };
+        if start > 0 {
+            parts.push(Part::Literal(&rest[..start]));
+        }
+        parts.push(Part::Param(rest[start + 1..start + len].trim()));
+        rest = &rest[start + len + 1..];
+    }
+    parts
+}
+
+/// Replaces the `{{` & `}}` escapes of a literal part.
+fn unescape(literal: &str) -> String {
+    literal.replace("{{", "{").replace("}}", "}")
+}
+
+fn params(template: &str) -> Vec<&str> {
+    parts(template)
+        .into_iter()
+        .filter_map(|part| match part {
+            Part::Param(name) => Some(name),
+            Part::Literal(_) => None,
+        })
+        .collect()
+}
+
+fn route(template: &str) -> String {
+    parts(template)
+        .into_iter()
+        .map(|part| match part {
+            Part::Literal(literal) => unescape(literal),
+            Part::Param(name) => format!(":{name}"),
+        })
+        .collect()
+}
+
+fn path<S: AsRef<str>>(template: &str, values: &[S]) -> String {
+    let mut values = values.iter();
+    parts(template)
+        .into_iter()
+        .map(|part| match part {
+            Part::Literal(literal) => unescape(literal),
+            Part::Param(name) => {
+                let value = values
+                    .next()
+                    .unwrap_or_else(|| panic!("missing a value for `{{{name}}}` of the path"));
+                encode_segment(value.as_ref())
+            }
+        })
+        .collect()
+}
+
+/// A [`Resource`] with a [`RouteTemplate`] for its routes & URLs.
+///
+/// [`DeriveResource`] implements it with `#[skipper(resource(path = "..."))]`, along with a
+/// [`Location`] of the `Model` rendered by [`RouteTemplate::item_path()`].
+pub trait ResourceRoute: Resource {
+    /// The path template of the resource's items (e.g. `"/cakes/{id}"`).
+    const ROUTE: RouteTemplate;
+}
+
+/// A base URL making the paths of [`RouteTemplate`]s & [`Location`]s absolute (e.g.
+/// `https://api.example.com/v1`).
+///
+/// The [`Default`] base URL keeps paths relative.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BaseUrl(Option<String>);
+
+impl BaseUrl {
+    /// Constructs a [`BaseUrl`] prefixing paths with `url` (without a trailing `/`).
+    #[must_use]
+    pub fn new(url: impl Into<String>) -> Self {
+        let mut url = url.into();
+        url.truncate(url.trim_end_matches('/').len());
+        Self(Some(url))
+    }
+
+    /// Constructs a [`BaseUrl`] prefixing paths with `url` if it is `Some`, and otherwise keeping
+    /// paths relative (e.g. for an optional configuration value).
+    #[must_use]
+    pub fn from_option(url: Option<impl Into<String>>) -> Self {
+        url.map_or_else(Self::default, Self::new)
+    }
+
+    /// Returns the base URL (if any).
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        self.0.as_deref()
+    }
+
+    /// Returns `path` prefixed with the base URL.
+    #[must_use]
+    pub fn url(&self, path: &str) -> String {
+        match &self.0 {
+            Some(base_url) => format!("{base_url}{path}"),
+            None => path.to_owned(),
+        }
+    }
+
+    /// Returns the [`Location`] of `data` prefixed with the base URL.
+    #[must_use]
+    pub fn location<L: Location + ?Sized>(&self, data: &L) -> String {
+        self.url(&data.location())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Renders parts as `"literal"` & `{param}` for comparisons.
+    fn rendered_parts(template: &str) -> Vec<String> {
+        parts(template)
+            .into_iter()
+            .map(|part| match part {
+                Part::Literal(literal) => format!("{literal:?}"),
+                Part::Param(name) => format!("{{{name}}}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parts_of_template() {
+        assert_eq!(rendered_parts("/cakes/{id}"), [r#""/cakes/""#, "{id}"]);
+        assert_eq!(
+            rendered_parts("/cakes/{ cake_id }/slices/{id}.json"),
+            [
+                r#""/cakes/""#,
+                "{cake_id}",
+                r#""/slices/""#,
+                "{id}",
+                r#"".json""#
+            ]
+        );
+        assert!(parts("").is_empty());
+    }
+
+    #[test]
+    fn parts_with_escaped_braces() {
+        assert_eq!(
+            rendered_parts("/a{{b}}/{id}"),
+            [r#""/a{{""#, r#""b}}/""#, "{id}"]
+        );
+        assert_eq!(params("/a{{b}}/{id}"), ["id"]);
+        assert_eq!(route("/a{{b}}/{id}"), "/a{b}/:id");
+        assert_eq!(path("/a{{b}}/{id}", &["1"]), "/a{b}/1");
+    }
+
+    #[test]
+    fn parts_with_unclosed_brace() {
+        assert_eq!(rendered_parts("/cakes/{id"), [r#""/cakes/{id""#]);
+        assert!(params("/cakes/{id").is_empty());
+        assert_eq!(route("/cakes/{id"), "/cakes/{id");
+        assert_eq!(path::<&str>("/cakes/{id", &[]), "/cakes/{id");
+    }
+
+    #[test]
+    fn path_encodes_values() {
+        assert_eq!(
+            path("/cakes/{id}", &["crème brûlée/2"]),
+            "/cakes/cr%C3%A8me%20br%C3%BBl%C3%A9e%2F2"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "missing a value for `{id}` of the path")]
+    fn path_with_missing_value() {
+        let _ = path("/cakes/{cake_id}/slices/{id}", &["1"]);
+    }
+
+    #[test]
+    fn nested_collection() {
+        let route = RouteTemplate::new("/cakes/{cake_id}/slices/{id}/");
+        assert_eq!(route.params(), ["cake_id", "id"]);
+        assert_eq!(route.collection_params(), ["cake_id"]);
+        assert_eq!(route.collection_route(), "/cakes/:cake_id/slices");
+        assert_eq!(route.collection_path(&["7"]), "/cakes/7/slices");
+    }
+
+    #[test]
+    fn collection_of_composite_key() {
+        let route = RouteTemplate::new("/links/{a}/{type}");
+        assert_eq!(route.collection_route(), "/links");
+        assert!(route.collection_params().is_empty());
+    }
+
+    #[test]
+    fn base_url() {
+        let base_url = BaseUrl::new("https://api.example.com/v1/");
+        assert_eq!(base_url.as_str(), Some("https://api.example.com/v1"));
+        assert_eq!(
+            base_url.url("/cakes/1"),
+            "https://api.example.com/v1/cakes/1"
+        );
+        assert_eq!(
+            BaseUrl::from_option(None::<String>).url("/cakes/1"),
+            "/cakes/1"
+        );
+    }
+}
@@ -0,0 +1,66 @@
+//! A `cake` entity implementing [`Resource`](crate::Resource) for unit tests.
+// Some helpers are only used by tests of optional features.
+#![allow(dead_code)]
+
+pub mod cake {
+    use sea_orm::entity::prelude::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Deserialize, Serialize)]
+    #[sea_orm(table_name = "cake")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        #[sea_orm(unique)]
+        pub name: String,
+        pub note: Option<String>,
+        /// Never serialized (e.g. like a password hash).
+        #[serde(skip)]
+        pub secret: String,
+        pub created_at: i64,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+
+    impl crate::Resource for Entity {
+        type ActiveModel = ActiveModel;
+        type Data = Model;
+        type View = Model;
+        type Id = i32;
+
+        fn alternate_key() -> Option<Column> {
+            Some(Column::Name)
+        }
+    }
+
+    /// Query parameters of cakes (`secret` and `note` can't be filtered, sorted or selected).
+    #[derive(Debug)]
+    pub struct CakeParams;
+
+    impl crate::query_filter::QueryParams for CakeParams {
+        type Entity = Entity;
+
+        fn column(query_param: &str) -> Option<Column> {
+            match query_param {
+                "id" => Some(Column::Id),
+                "name" => Some(Column::Name),
+                "created_at" => Some(Column::CreatedAt),
+                _ => None,
+            }
+        }
+    }
+
+    /// Returns a stored cake.
+    pub fn model() -> Model {
+        Model {
+            id: 1,
+            name: "Pancake".to_string(),
+            note: None,
+            secret: "s3cr3t".to_string(),
+            created_at: 1_700_000_000,
+        }
+    }
+}
@@ -0,0 +1,365 @@
+//! The `validation` module validates data models (e.g. `NewModel` and `PatchModel` structs) before
+//! they are written to the database.
+//!
+//! [`Validate`](crate::validation::Validate) is implemented by `DeriveNewModel` and
+//! `DerivePatchModel` using `#[skipper(validate(...))]` field attributes. The validator functions
+//! in this module are called by the derived implementations and may also be used in hand-written
+//! `Validate` implementations.
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt::{self, Display};
+
+use serde::Serialize;
+
+#[cfg(feature = "regex")]
+pub use regex::Regex;
+
+/// A trait for validating a data model's field values.
+pub trait Validate {
+    /// Validates all fields of `self`.
+    ///
+    /// # Errors
+    /// Returns [`ValidationErrors`] with an error for every invalid field value (not just the first).
+    fn validate(&self) -> Result<(), ValidationErrors>;
+}
+
+/// An invalid field value.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ValidationError {
+    /// Path of the invalid field (i.e. its serialized field name).
+    pub path: String,
+    /// Code of the failed validation (e.g. `length` or `email`).
+    pub code: String,
+    /// Description of why the value is invalid.
+    pub message: String,
+}
+
+impl ValidationError {
+    /// Constructs a [`ValidationError`] without a path (the path is set by [`ValidationErrors::add()`]).
+    #[must_use]
+    pub fn new(code: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            path: String::new(),
+            code: code.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// All invalid field values of a data model (serialized as a list of [`ValidationError`]s).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(transparent)]
+pub struct ValidationErrors(Vec<ValidationError>);
+
+impl ValidationErrors {
+    /// Adds the error of a validator's `result` (if any) for the field at `path`.
+    pub fn add(&mut self, path: &str, result: Result<(), ValidationError>) {
+        if let Err(mut error) = result {
+            path.clone_into(&mut error.path);
+            self.0.push(error);
+        }
+    }
+
+    /// Returns true if there are no errors.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the errors.
+    #[must_use]
+    pub fn errors(&self) -> &[ValidationError] {
+        &self.0
+    }
+
+    /// Returns `Ok(())` if there are no errors, otherwise `Err(self)`.
+    ///
+    /// # Errors
+    /// Returns `self` if there are errors.
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, error) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{error}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
+/// A value with a length that can be validated with [`length()`].
+///
+/// The length of strings is their number of characters (not bytes).
+pub trait HasLength {
+    /// Returns the length of `self`.
+    fn length(&self) -> usize;
+}
+
+impl HasLength for str {
+    fn length(&self) -> usize {
+        self.chars().count()
+    }
+}
+
+impl HasLength for String {
+    fn length(&self) -> usize {
+        self.as_str().length()
+    }
+}
+
+impl<T> HasLength for [T] {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> HasLength for Vec<T> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K, V, S> HasLength for HashMap<K, V, S> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T, S> HasLength for HashSet<T, S> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K, V> HasLength for BTreeMap<K, V> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> HasLength for BTreeSet<T> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T: HasLength + ?Sized> HasLength for &T {
+    fn length(&self) -> usize {
+        (**self).length()
+    }
+}
+
+/// Validates that the length of `value` is at least `min` and at most `max`.
+///
+/// # Errors
+/// Returns a `length` [`ValidationError`] if the length is out of bounds.
+pub fn length<T: HasLength + ?Sized>(
+    value: &T,
+    min: Option<usize>,
+    max: Option<usize>,
+) -> Result<(), ValidationError> {
+    let length = value.length();
+    if min.is_some_and(|min| length < min) || max.is_some_and(|max| length > max) {
+        return Err(ValidationError::new(
+            "length",
+            bounds_message("length", min, max),
+        ));
+    }
+    Ok(())
+}
+
+/// Validates that `value` is at least `min` and at most `max`.
+///
+/// # Errors
+/// Returns a `range` [`ValidationError`] if the value is out of bounds.
+pub fn range<T: PartialOrd + Display>(
+    value: &T,
+    min: Option<T>,
+    max: Option<T>,
+) -> Result<(), ValidationError> {
+    if min.as_ref().is_some_and(|min| value < min) || max.as_ref().is_some_and(|max| value > max) {
+        return Err(ValidationError::new(
+            "range",
+            bounds_message("value", min, max),
+        ));
+    }
+    Ok(())
+}
+
+/// Validates that `value` looks like an email address (`local@domain` with a dotted domain).
+///
+/// This is a syntax check only; it does not verify that the address exists.
+///
+/// # Errors
+/// Returns an `email` [`ValidationError`] if the value is not an email address.
+pub fn email<T: AsRef<str> + ?Sized>(value: &T) -> Result<(), ValidationError> {
+    let value = value.as_ref();
+    let valid = match value.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && local.len() <= 64
+                && !domain.contains('@')
+                && domain.contains('.')
+                && domain.split('.').all(|label| {
+                    !label.is_empty()
+                        && !label.starts_with('-')
+                        && !label.ends_with('-')
+                        && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+                })
+                && !local.chars().any(char::is_whitespace)
+        }
+        None => false,
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(ValidationError::new("email", "must be an email address"))
+    }
+}
+
+/// Validates that `value` matches `regex`.
+///
+/// # Errors
+/// Returns a `regex` [`ValidationError`] if the value does not match.
+#[cfg(feature = "regex")]
+pub fn regex<T: AsRef<str> + ?Sized>(value: &T, regex: &Regex) -> Result<(), ValidationError> {
+    if regex.is_match(value.as_ref()) {
+        Ok(())
+    } else {
+        Err(ValidationError::new(
+            "regex",
+            format!("must match `{}`", regex.as_str()),
+        ))
+    }
+}
+
+fn bounds_message<T: Display>(subject: &str, min: Option<T>, max: Option<T>) -> String {
+    match (min, max) {
+        (Some(min), Some(max)) => format!("{subject} must be between {min} and {max}"),
+        (Some(min), None) => format!("{subject} must be at least {min}"),
+        (None, Some(max)) => format!("{subject} must be at most {max}"),
+        (None, None) => format!("{subject} is invalid"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn length_counts_characters() {
+        assert!(length("añb", Some(3), Some(3)).is_ok());
+        assert!(length(&vec![1, 2], Some(1), None).is_ok());
+        assert!(length("", None, Some(0)).is_ok());
+    }
+
+    #[test]
+    fn length_out_of_bounds() {
+        let error = length("abc", Some(4), Some(10)).unwrap_err();
+        assert_eq!(error.code, "length");
+        assert_eq!(error.message, "length must be between 4 and 10");
+        assert_eq!(
+            length("abc", None, Some(2)).unwrap_err().message,
+            "length must be at most 2"
+        );
+        assert_eq!(
+            length(&Vec::<u8>::new(), Some(1), None)
+                .unwrap_err()
+                .message,
+            "length must be at least 1"
+        );
+    }
+
+    #[test]
+    fn range_bounds_are_inclusive() {
+        assert!(range(&0, Some(0), Some(10)).is_ok());
+        assert!(range(&10, Some(0), Some(10)).is_ok());
+        assert!(range(&-5, None, Some(0)).is_ok());
+    }
+
+    #[test]
+    fn range_out_of_bounds() {
+        let error = range(&11, Some(0), Some(10)).unwrap_err();
+        assert_eq!(error.code, "range");
+        assert_eq!(error.message, "value must be between 0 and 10");
+        assert_eq!(
+            range(&0.5, Some(1.0), None).unwrap_err().message,
+            "value must be at least 1"
+        );
+    }
+
+    #[test]
+    fn email_valid() {
+        for value in [
+            "a@example.com",
+            "first.last+tag@sub.example.co",
+            "ü@bücher.de",
+        ] {
+            assert!(email(value).is_ok(), "{value}");
+        }
+    }
+
+    #[test]
+    fn email_invalid() {
+        for value in [
+            "",
+            "example.com",
+            "@example.com",
+            "a@example",
+            "a@b@example.com",
+            "a@.example.com",
+            "a@example..com",
+            "a@-example.com",
+            "a b@example.com",
+            "a@exa mple.com",
+        ] {
+            let error = email(value).unwrap_err();
+            assert_eq!(error.code, "email", "{value}");
+        }
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn regex_matches() {
+        let pattern = Regex::new("^[a-z]+$").unwrap();
+        assert!(regex("cake", &pattern).is_ok());
+        let error = regex("Cake", &pattern).unwrap_err();
+        assert_eq!(error.code, "regex");
+        assert_eq!(error.message, "must match `^[a-z]+$`");
+    }
+
+    #[test]
+    fn errors_are_collected_with_paths() {
+        let mut errors = ValidationErrors::default();
+        errors.add("name", length("", Some(1), None));
+        errors.add("price", range(&5, Some(0), None));
+        errors.add("email", email("nope"));
+        let errors = errors.into_result().unwrap_err();
+        let paths: Vec<_> = errors.errors().iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(paths, ["name", "email"]);
+        assert_eq!(
+            errors.to_string(),
+            "name: length must be at least 1; email: must be an email address"
+        );
+        assert!(ValidationErrors::default().into_result().is_ok());
+    }
+}